@@ -0,0 +1,454 @@
+#!/usr/bin/env python3
+"""Generates the Unicode tables in `src/tables` from the Unicode Character
+Database bundled with Python.
+
+Usage: python3 scripts/unicode.py
+"""
+
+import os
+import unicodedata
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..")
+TABLES = os.path.join(ROOT, "src", "tables")
+
+# The property lists below are copied from the Unicode Character Database for
+# this version, so the tables must be generated from the same version.
+UNICODE_VERSION = "14.0.0"
+assert unicodedata.unidata_version == UNICODE_VERSION, (
+    "expected Unicode {}, but Python bundles Unicode {}".format(
+        UNICODE_VERSION, unicodedata.unidata_version
+    )
+)
+
+HEADER = """\
+// This file is generated by `scripts/unicode.py`. Do not edit it by hand.
+//
+// Unicode version: {version}
+""".format(version=UNICODE_VERSION)
+
+# Python does not expose the simple case mappings from `UnicodeData.txt`.
+# These are the simple mappings of the characters whose full mappings, from
+# `SpecialCasing.txt`, are longer than a single character. Characters not
+# listed here have no simple mapping.
+SIMPLE_UPPERCASE = {
+    **{c: c + 8 for c in range(0x1F80, 0x1F88)},
+    **{c: c + 8 for c in range(0x1F90, 0x1F98)},
+    **{c: c + 8 for c in range(0x1FA0, 0x1FA8)},
+    0x1FB3: 0x1FBC,
+    0x1FC3: 0x1FCC,
+    0x1FF3: 0x1FFC,
+}
+SIMPLE_LOWERCASE = {
+    0x0130: 0x0069,
+}
+
+# Python does not expose the properties used to derive the
+# `Grapheme_Cluster_Break` property, so these are taken from the Unicode
+# Character Database for the same version.
+OTHER_GRAPHEME_EXTEND = [
+    (0x09BE, 0x09BE), (0x09D7, 0x09D7), (0x0B3E, 0x0B3E), (0x0B57, 0x0B57),
+    (0x0BBE, 0x0BBE), (0x0BD7, 0x0BD7), (0x0CC2, 0x0CC2), (0x0CD5, 0x0CD6),
+    (0x0D3E, 0x0D3E), (0x0D57, 0x0D57), (0x0DCF, 0x0DCF), (0x0DDF, 0x0DDF),
+    (0x1B35, 0x1B35), (0x200C, 0x200C), (0x302E, 0x302F), (0xFF9E, 0xFF9F),
+    (0x1133E, 0x1133E), (0x11357, 0x11357), (0x114B0, 0x114B0), (0x114BD, 0x114BD),
+    (0x115AF, 0x115AF), (0x11930, 0x11930), (0x1D165, 0x1D165), (0x1D16E, 0x1D172),
+    (0xE0020, 0xE007F),
+]
+EMOJI_MODIFIER = [(0x1F3FB, 0x1F3FF)]
+PREPEND = [
+    (0x0600, 0x0605), (0x06DD, 0x06DD), (0x070F, 0x070F), (0x0890, 0x0891),
+    (0x08E2, 0x08E2), (0x0D4E, 0x0D4E), (0x110BD, 0x110BD), (0x110CD, 0x110CD),
+    (0x111C2, 0x111C3), (0x1193F, 0x1193F), (0x11941, 0x11941), (0x11A3A, 0x11A3A),
+    (0x11A84, 0x11A89), (0x11D46, 0x11D46),
+]
+SPACING_MARK_EXCEPTIONS = [
+    (0x102B, 0x102C), (0x1038, 0x1038), (0x1062, 0x1064), (0x1067, 0x106D),
+    (0x1083, 0x1083), (0x1087, 0x108C), (0x108F, 0x108F), (0x109A, 0x109C),
+    (0x1A61, 0x1A61), (0x1A63, 0x1A64), (0xAA7B, 0xAA7B), (0xAA7D, 0xAA7D),
+    (0x11720, 0x11721),
+]
+UNASSIGNED_DEFAULT_IGNORABLE = [
+    (0x2065, 0x2065), (0xFFF0, 0xFFF8), (0xE0000, 0xE0000), (0xE0002, 0xE001F),
+    (0xE0080, 0xE00FF), (0xE01F0, 0xE0FFF),
+]
+EXTENDED_PICTOGRAPHIC = [
+    (0x00A9, 0x00A9), (0x00AE, 0x00AE), (0x203C, 0x203C), (0x2049, 0x2049),
+    (0x2122, 0x2122), (0x2139, 0x2139), (0x2194, 0x2199), (0x21A9, 0x21AA),
+    (0x231A, 0x231B), (0x2328, 0x2328), (0x2388, 0x2388), (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3), (0x23F8, 0x23FA), (0x24C2, 0x24C2), (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6), (0x25C0, 0x25C0), (0x25FB, 0x25FE), (0x2600, 0x2605),
+    (0x2607, 0x2612), (0x2614, 0x2685), (0x2690, 0x2705), (0x2708, 0x2712),
+    (0x2714, 0x2714), (0x2716, 0x2716), (0x271D, 0x271D), (0x2721, 0x2721),
+    (0x2728, 0x2728), (0x2733, 0x2734), (0x2744, 0x2744), (0x2747, 0x2747),
+    (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755), (0x2757, 0x2757),
+    (0x2763, 0x2767), (0x2795, 0x2797), (0x27A1, 0x27A1), (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF), (0x2934, 0x2935), (0x2B05, 0x2B07), (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50), (0x2B55, 0x2B55), (0x3030, 0x3030), (0x303D, 0x303D),
+    (0x3297, 0x3297), (0x3299, 0x3299), (0x1F000, 0x1F0FF), (0x1F10D, 0x1F10F),
+    (0x1F12F, 0x1F12F), (0x1F16C, 0x1F171), (0x1F17E, 0x1F17F), (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A), (0x1F1AD, 0x1F1E5), (0x1F201, 0x1F20F), (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F), (0x1F232, 0x1F23A), (0x1F23C, 0x1F23F), (0x1F249, 0x1F3FA),
+    (0x1F400, 0x1F53D), (0x1F546, 0x1F64F), (0x1F680, 0x1F6FF), (0x1F774, 0x1F77F),
+    (0x1F7D5, 0x1F7FF), (0x1F80C, 0x1F80F), (0x1F848, 0x1F84F), (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F), (0x1F8AE, 0x1F8FF), (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945),
+    (0x1F947, 0x1FAFF), (0x1FC00, 0x1FFFD),
+]
+
+# Python reports every unassigned character as `Fullwidth`, but the
+# `East_Asian_Width` property of unassigned characters is `Wide` in these
+# ranges and `Neutral` elsewhere.
+UNASSIGNED_WIDE = [
+    (0x3400, 0x4DBF), (0x4E00, 0x9FFF), (0xF900, 0xFAFF), (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+]
+
+
+def expand(ranges):
+    """Expands a list of inclusive ranges into a set of code points."""
+    return {c for start, end in ranges for c in range(start, end + 1)}
+
+
+def scalars():
+    """Returns every Unicode scalar value."""
+    return (c for c in range(0x110000) if not 0xD800 <= c < 0xE000)
+
+
+def ranges(codes):
+    """Collapses a set of code points into sorted, inclusive ranges."""
+    result = []
+    for c in sorted(codes):
+        if result and result[-1][1] + 1 == c:
+            result[-1][1] = c
+        else:
+            result.append([c, c])
+    return result
+
+
+def char(c):
+    """Formats a code point as a Rust `char` literal."""
+    return "'\\u{{{:X}}}'".format(c)
+
+
+def range_table(name, doc, codes):
+    """Formats a table of inclusive character ranges."""
+    lines = ["", "/// {}".format(doc), "pub(crate) const {}: &[(char, char)] = &[".format(name)]
+    for start, end in ranges(codes):
+        lines.append("    ({}, {}),".format(char(start), char(end)))
+    lines.append("];")
+    return "\n".join(lines) + "\n"
+
+
+def mapping_table(name, doc, mapping, width):
+    """Formats a table mapping characters to fixed-length character arrays."""
+    lines = ["", "/// {}".format(doc), "pub(crate) const {}: &[(char, [char; {}])] = &[".format(name, width)]
+    for c in sorted(mapping):
+        to = [ord(t) for t in mapping[c]] + [0] * (width - len(mapping[c]))
+        lines.append("    ({}, [{}]),".format(char(c), ", ".join(char(t) for t in to)))
+    lines.append("];")
+    return "\n".join(lines) + "\n"
+
+
+def category_table(name, doc, ty, categories):
+    """Formats a table of inclusive character ranges with a category."""
+    lines = ["", "/// {}".format(doc), "pub(crate) const {}: &[(char, char, {})] = &[".format(name, ty)]
+    result = []
+    for c in sorted(categories):
+        if result and result[-1][1] + 1 == c and result[-1][2] == categories[c]:
+            result[-1][1] = c
+        else:
+            result.append([c, c, categories[c]])
+    for start, end, category in result:
+        lines.append("    ({}, {}, {}::{}),".format(char(start), char(end), ty, category))
+    lines.append("];")
+    return "\n".join(lines) + "\n"
+
+
+def value_table(name, doc, ty, values):
+    """Formats a table of inclusive character ranges with a value."""
+    lines = ["", "/// {}".format(doc), "pub(crate) const {}: &[(char, char, {})] = &[".format(name, ty)]
+    result = []
+    for c in sorted(values):
+        if result and result[-1][1] + 1 == c and result[-1][2] == values[c]:
+            result[-1][1] = c
+        else:
+            result.append([c, c, values[c]])
+    for start, end, value in result:
+        lines.append("    ({}, {}, {}),".format(char(start), char(end), value))
+    lines.append("];")
+    return "\n".join(lines) + "\n"
+
+
+def slice_table(name, doc, mapping):
+    """Formats a table mapping characters to character slices."""
+    lines = ["", "/// {}".format(doc), "pub(crate) const {}: &[(char, &[char])] = &[".format(name)]
+    for c in sorted(mapping):
+        lines.append("    ({}, &[{}]),".format(char(c), ", ".join(char(ord(t)) for t in mapping[c])))
+    lines.append("];")
+    return "\n".join(lines) + "\n"
+
+
+def pair_table(name, doc, mapping):
+    """Formats a table mapping characters to characters."""
+    lines = ["", "/// {}".format(doc), "pub(crate) const {}: &[(char, char)] = &[".format(name)]
+    for c in sorted(mapping):
+        lines.append("    ({}, {}),".format(char(c), char(mapping[c])))
+    lines.append("];")
+    return "\n".join(lines) + "\n"
+
+
+def write(name, *tables, imports=()):
+    path = os.path.join(TABLES, name)
+    with open(path, "w", encoding="utf-8") as f:
+        f.write(HEADER)
+        if imports:
+            f.write("\n")
+        for path in imports:
+            f.write("use {};\n".format(path))
+        for table in tables:
+            f.write(table)
+
+
+def case():
+    uppercase = {}
+    lowercase = {}
+    titlecase = {}
+    for c in scalars():
+        s = chr(c)
+        if s.upper() != s:
+            uppercase[c] = s.upper()
+        if s.lower() != s:
+            lowercase[c] = s.lower()
+        if s.title() != s.upper():
+            titlecase[c] = s.title()
+
+    simple_uppercase = {c: SIMPLE_UPPERCASE.get(c, c) for c in uppercase if len(uppercase[c]) > 1}
+    simple_lowercase = {c: SIMPLE_LOWERCASE.get(c, c) for c in lowercase if len(lowercase[c]) > 1}
+
+    # The `Cased` and `Case_Ignorable` properties are only exposed through the
+    # handling of a final capital sigma when lowercasing.
+    def is_final_sigma(s):
+        return s.lower()[-1] == "ς"
+
+    cased = set()
+    case_ignorable = set()
+    for c in scalars():
+        s = chr(c)
+        if is_final_sigma(s + "Σ"):
+            cased.add(c)
+        elif is_final_sigma("A" + s + "Σ"):
+            case_ignorable.add(c)
+
+    write(
+        "case.rs",
+        mapping_table("UPPERCASE", "Full uppercase mappings.", uppercase, 3),
+        mapping_table("LOWERCASE", "Full lowercase mappings.", lowercase, 3),
+        mapping_table(
+            "TITLECASE",
+            "Full titlecase mappings of characters whose titlecase and uppercase mappings differ.",
+            titlecase,
+            3,
+        ),
+        pair_table(
+            "SIMPLE_UPPERCASE",
+            "Simple uppercase mappings of characters with multi-character full mappings.",
+            simple_uppercase,
+        ),
+        pair_table(
+            "SIMPLE_LOWERCASE",
+            "Simple lowercase mappings of characters with multi-character full mappings.",
+            simple_lowercase,
+        ),
+        range_table(
+            "CASED",
+            "Characters with the `Cased` property, but not the `Case_Ignorable` property.",
+            cased,
+        ),
+        range_table("CASE_IGNORABLE", "Characters with the `Case_Ignorable` property.", case_ignorable),
+    )
+
+
+def grapheme():
+    other_grapheme_extend = expand(OTHER_GRAPHEME_EXTEND)
+    emoji_modifier = expand(EMOJI_MODIFIER)
+    prepend = expand(PREPEND)
+    spacing_mark_exceptions = expand(SPACING_MARK_EXCEPTIONS)
+    unassigned_default_ignorable = expand(UNASSIGNED_DEFAULT_IGNORABLE)
+
+    categories = {}
+    for c in scalars():
+        gc = unicodedata.category(chr(c))
+        if c == 0x0D:
+            category = "CR"
+        elif c == 0x0A:
+            category = "LF"
+        elif c == 0x200D:
+            category = "ZWJ"
+        elif gc in ("Mn", "Me") or c in other_grapheme_extend or c in emoji_modifier:
+            category = "Extend"
+        elif c in prepend:
+            category = "Prepend"
+        elif (gc in ("Zl", "Zp", "Cc", "Cf") and c != 0x200C) or c in unassigned_default_ignorable:
+            category = "Control"
+        elif 0x1F1E6 <= c <= 0x1F1FF:
+            category = "RegionalIndicator"
+        elif (gc == "Mc" and c not in spacing_mark_exceptions) or c in (0x0E33, 0x0EB3):
+            category = "SpacingMark"
+        elif 0x1100 <= c <= 0x115F or 0xA960 <= c <= 0xA97C:
+            category = "L"
+        elif 0x1160 <= c <= 0x11A7 or 0xD7B0 <= c <= 0xD7C6:
+            category = "V"
+        elif 0x11A8 <= c <= 0x11FF or 0xD7CB <= c <= 0xD7FB:
+            category = "T"
+        elif 0xAC00 <= c <= 0xD7A3:
+            category = "LV" if (c - 0xAC00) % 28 == 0 else "LVT"
+        else:
+            continue
+        categories[c] = category
+
+    write(
+        "grapheme.rs",
+        category_table(
+            "GRAPHEME_CLUSTER_BREAK",
+            "Characters with a `Grapheme_Cluster_Break` property other than `Other`.",
+            "GraphemeClusterBreak",
+            categories,
+        ),
+        range_table(
+            "EXTENDED_PICTOGRAPHIC",
+            "Characters with the `Extended_Pictographic` property.",
+            expand(EXTENDED_PICTOGRAPHIC),
+        ),
+        imports=["crate::grapheme::GraphemeClusterBreak"],
+    )
+
+
+def escape():
+    # The characters that `char::escape_debug` treats as printable.
+    not_printable = set()
+    for c in scalars():
+        if unicodedata.category(chr(c)) in ("Cc", "Cf", "Cs", "Co", "Cn", "Zl", "Zp", "Zs") and c != 0x20:
+            not_printable.add(c)
+
+    grapheme_extend = set()
+    for c in scalars():
+        if unicodedata.category(chr(c)) in ("Mn", "Me"):
+            grapheme_extend.add(c)
+    grapheme_extend |= expand(OTHER_GRAPHEME_EXTEND)
+
+    write(
+        "escape.rs",
+        range_table(
+            "NOT_PRINTABLE",
+            "Characters in the `Cc`, `Cf`, `Cs`, `Co`, `Cn`, `Zl`, `Zp` and `Zs` categories, except space.",
+            not_printable,
+        ),
+        range_table("GRAPHEME_EXTEND", "Characters with the `Grapheme_Extend` property.", grapheme_extend),
+    )
+
+
+def normalize():
+    def is_hangul_syllable(c):
+        return 0xAC00 <= c <= 0xD7A3
+
+    combining_class = {}
+    canonical = {}
+    compatibility = {}
+    for c in scalars():
+        s = chr(c)
+        if unicodedata.combining(s):
+            combining_class[c] = unicodedata.combining(s)
+        if is_hangul_syllable(c):
+            continue
+        nfd = unicodedata.normalize("NFD", s)
+        nfkd = unicodedata.normalize("NFKD", s)
+        if nfd != s:
+            canonical[c] = nfd
+        if nfkd != nfd:
+            compatibility[c] = nfkd
+
+    # Primary composites, excluding characters that are not recomposed by NFC.
+    composition = {}
+    for c in scalars():
+        decomposition = unicodedata.decomposition(chr(c))
+        if not decomposition or decomposition.startswith("<") or is_hangul_syllable(c):
+            continue
+        parts = [int(part, 16) for part in decomposition.split()]
+        if len(parts) == 2 and unicodedata.normalize("NFC", chr(parts[0]) + chr(parts[1])) == chr(c):
+            composition.setdefault(parts[0], []).append((parts[1], c))
+
+    lines = [
+        "",
+        "/// Canonical compositions of pairs of characters, by first and then second character.",
+        "pub(crate) const COMPOSITION: &[(char, &[(char, char)])] = &[",
+    ]
+    for first in sorted(composition):
+        pairs = ", ".join("({}, {})".format(char(b), char(c)) for b, c in sorted(composition[first]))
+        lines.append("    ({}, &[{}]),".format(char(first), pairs))
+    lines.append("];")
+    composition_table = "\n".join(lines) + "\n"
+
+    write(
+        "normalize.rs",
+        value_table(
+            "COMBINING_CLASS",
+            "Characters with a `Canonical_Combining_Class` property other than zero.",
+            "u8",
+            combining_class,
+        ),
+        slice_table(
+            "CANONICAL_DECOMPOSITION",
+            "Full canonical decompositions, excluding Hangul syllables.",
+            canonical,
+        ),
+        slice_table(
+            "COMPATIBILITY_DECOMPOSITION",
+            "Full compatibility decompositions that differ from the canonical decompositions.",
+            compatibility,
+        ),
+        composition_table,
+    )
+
+
+def width():
+    unassigned_wide = expand(UNASSIGNED_WIDE)
+
+    zero_width = set()
+    wide = set()
+    for c in scalars():
+        s = chr(c)
+        category = unicodedata.category(s)
+        if (category in ("Cc", "Mn", "Me", "Cf") and c != 0xAD) or 0x1160 <= c <= 0x11FF or c == 0x200B:
+            zero_width.add(c)
+        elif category == "Cn":
+            if c in unassigned_wide:
+                wide.add(c)
+        elif unicodedata.east_asian_width(s) in ("W", "F"):
+            wide.add(c)
+
+    write(
+        "width.rs",
+        range_table(
+            "ZERO_WIDTH",
+            "Control characters, combining marks, format characters except soft hyphen, "
+            "and Hangul medial vowels and final consonants.",
+            zero_width,
+        ),
+        range_table(
+            "WIDE",
+            "Characters with an `East_Asian_Width` property of `Wide` or `Fullwidth`, "
+            "excluding zero width characters.",
+            wide,
+        ),
+    )
+
+
+def main():
+    case()
+    grapheme()
+    escape()
+    normalize()
+    width()
+
+
+if __name__ == "__main__":
+    main()
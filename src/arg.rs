@@ -0,0 +1,123 @@
+use core::ops::{Range, RangeInclusive};
+
+use crate::buf::Buf;
+use crate::int::{self, Int};
+use crate::utf8;
+
+/// The maximum number of characters in a character range argument.
+const MAX_RANGE_LEN: u32 = 4096;
+
+/// A wrapper used to dispatch on the type of a [`chstr!`](crate::chstr)
+/// argument.
+///
+/// Each supported argument type has an inherent `write` method, so the method
+/// is selected by the type of the wrapped value.
+pub struct Arg<T>(pub T);
+
+impl Arg<char> {
+    /// Writes the character to the buffer.
+    pub const fn write<const N: usize>(self, buf: Buf<N>) -> Buf<N> {
+        buf.push_char(self.0)
+    }
+}
+
+impl Arg<&str> {
+    /// Writes the string slice to the buffer.
+    pub const fn write<const N: usize>(self, buf: Buf<N>) -> Buf<N> {
+        buf.push_str(self.0)
+    }
+}
+
+impl Arg<Range<char>> {
+    /// Writes each character in the range to the buffer.
+    pub const fn write<const N: usize>(self, buf: Buf<N>) -> Buf<N> {
+        write_range(buf, self.0.start as u32, self.0.end as u32)
+    }
+}
+
+impl Arg<RangeInclusive<char>> {
+    /// Writes each character in the range to the buffer.
+    pub const fn write<const N: usize>(self, buf: Buf<N>) -> Buf<N> {
+        write_range(buf, *self.0.start() as u32, *self.0.end() as u32 + 1)
+    }
+}
+
+impl Arg<bool> {
+    /// Writes `true` or `false` to the buffer.
+    pub const fn write<const N: usize>(self, buf: Buf<N>) -> Buf<N> {
+        buf.push_str(if self.0 { "true" } else { "false" })
+    }
+}
+
+macro_rules! impl_int {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl Arg<$t> {
+                /// Writes the decimal representation of the integer to the buffer.
+                pub const fn write<const N: usize>(self, buf: Buf<N>) -> Buf<N> {
+                    Arg(Int::new(self.0)).write(buf)
+                }
+            }
+
+            impl Arg<Int<$t>> {
+                /// Writes the formatted integer to the buffer.
+                #[allow(unused_comparisons)]
+                pub const fn write<const N: usize>(self, buf: Buf<N>) -> Buf<N> {
+                    let Int { value, spec } = self.0;
+                    if value < 0 && spec.is_signed() {
+                        int::write(buf, true, (value as $u).wrapping_neg() as u128, spec)
+                    } else {
+                        int::write(buf, false, value as $u as u128, spec)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int! {
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+}
+
+/// Writes the characters with codes in the range `start..end` to the buffer,
+/// skipping surrogates.
+const fn write_range<const N: usize>(buf: Buf<N>, start: u32, end: u32) -> Buf<N> {
+    const SURROGATES: Range<u32> = 0xD800..0xE000;
+
+    if start >= end {
+        return buf;
+    }
+
+    let mut len = end - start;
+    if start < SURROGATES.end && end > SURROGATES.start {
+        let overlap_start = if start > SURROGATES.start {
+            start
+        } else {
+            SURROGATES.start
+        };
+        let overlap_end = if end < SURROGATES.end {
+            end
+        } else {
+            SURROGATES.end
+        };
+        len -= overlap_end - overlap_start;
+    }
+    assert!(
+        len <= MAX_RANGE_LEN,
+        "character range is longer than 4096 characters"
+    );
+
+    let mut buf = buf;
+    let mut code = start;
+    while code < end {
+        if code == SURROGATES.start {
+            code = SURROGATES.end;
+            continue;
+        }
+        // SAFETY: `code` is at most `char::MAX` and is not a surrogate.
+        buf = buf.push_char(unsafe { utf8::char_from_u32_unchecked(code) });
+        code += 1;
+    }
+    buf
+}
@@ -0,0 +1,229 @@
+use crate::buf::Buf;
+
+/// Encodes constant data as Base32, producing a constant `&str`.
+///
+/// The data may be a `&str`, a `&[u8]` or a `&[u8; N]`. By default, the
+/// standard alphabet from [RFC 4648] is used, with padding. Options may follow
+/// the data:
+///
+/// - `hex` uses the extended hex alphabet, `0`-`9` followed by `A`-`V`.
+/// - `lower` uses lowercase letters.
+/// - `no_pad` omits the trailing `=` padding.
+///
+/// [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_base32;
+/// const ENCODED: &str = chstr_base32!("foobar");
+///
+/// assert_eq!(ENCODED, "MZXW6YTBOI======");
+/// assert_eq!(chstr_base32!("foobar", hex), "CPNMUOJ1E8======");
+/// ```
+///
+/// Options:
+/// ```
+/// # use chstr::chstr_base32;
+/// const KEY: &[u8] = &[0x00, 0xFF, 0x10];
+///
+/// assert_eq!(chstr_base32!(KEY, lower, no_pad), "ad7ra");
+/// ```
+#[macro_export]
+macro_rules! chstr_base32 {
+    ($data:expr $(, $opt:ident)* $(,)?) => {{
+        const BYTES: &[u8] = $crate::__private::Bytes($data).get();
+        const OPTIONS: $crate::__private::Base32Options =
+            $crate::__private::Base32Options::new()$(.$opt())*;
+
+        $crate::__chstr_build!(|buf| $crate::__private::base32_encode(buf, BYTES, OPTIONS))
+    }};
+}
+
+/// Decodes a constant Base32 `&str`, producing a constant `&[u8; N]`.
+///
+/// Letters may be uppercase or lowercase. Accepts the same options as
+/// [`chstr_base32!`], which select the alphabet and whether the input must be
+/// padded. Invalid characters, incorrect padding and non-zero trailing bits are
+/// compile-time errors.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_base32_decode;
+/// const DATA: &[u8; 6] = chstr_base32_decode!("MZXW6YTBOI======");
+///
+/// assert_eq!(DATA, b"foobar");
+/// assert_eq!(chstr_base32_decode!("cpnmu", hex, no_pad), b"foo");
+/// ```
+///
+/// Invalid input fails to compile:
+/// ```compile_fail
+/// # use chstr::chstr_base32_decode;
+/// const BAD: &[u8] = chstr_base32_decode!("MZXW6Y==");
+/// ```
+#[macro_export]
+macro_rules! chstr_base32_decode {
+    ($s:expr $(, $opt:ident)* $(,)?) => {{
+        const STR: &str = $s;
+        const OPTIONS: $crate::__private::Base32Options =
+            $crate::__private::Base32Options::new()$(.$opt())*;
+
+        $crate::__chstr_build_bytes!(|buf| $crate::__private::base32_decode(buf, STR, OPTIONS))
+    }};
+}
+
+const STANDARD: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+
+/// Options for encoding and decoding Base32.
+#[derive(Clone, Copy)]
+pub struct Base32Options {
+    alphabet: &'static [u8; 32],
+    lower: bool,
+    pad: bool,
+}
+
+impl Base32Options {
+    /// Returns the default options.
+    pub const fn new() -> Base32Options {
+        Base32Options {
+            alphabet: STANDARD,
+            lower: false,
+            pad: true,
+        }
+    }
+
+    /// Uses the extended hex alphabet.
+    pub const fn hex(mut self) -> Base32Options {
+        self.alphabet = HEX;
+        self
+    }
+
+    /// Uses lowercase letters.
+    pub const fn lower(mut self) -> Base32Options {
+        self.lower = true;
+        self
+    }
+
+    /// Omits padding.
+    pub const fn no_pad(mut self) -> Base32Options {
+        self.pad = false;
+        self
+    }
+}
+
+impl Default for Base32Options {
+    fn default() -> Base32Options {
+        Base32Options::new()
+    }
+}
+
+/// Writes the Base32 encoding of the bytes to the buffer.
+pub const fn base32_encode<const N: usize>(
+    buf: Buf<N>,
+    bytes: &[u8],
+    options: Base32Options,
+) -> Buf<N> {
+    let mut buf = buf;
+    let mut digits = 0;
+
+    // Bits that have been read but not yet written.
+    let mut acc = 0u32;
+    let mut bits = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        acc = (acc << 8) | bytes[i] as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            buf = buf.push_byte(digit(options, (acc >> bits) as usize & 0x1F));
+            digits += 1;
+        }
+        acc &= (1 << bits) - 1;
+        i += 1;
+    }
+    if bits > 0 {
+        buf = buf.push_byte(digit(options, (acc << (5 - bits)) as usize & 0x1F));
+        digits += 1;
+    }
+
+    if options.pad {
+        while digits % 8 != 0 {
+            buf = buf.push_byte(b'=');
+            digits += 1;
+        }
+    }
+    buf
+}
+
+/// Writes the bytes decoded from a Base32 string to the buffer.
+///
+/// # Panics
+///
+/// Panics if the string is not valid Base32.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+pub const fn base32_decode<const N: usize>(buf: Buf<N>, s: &str, options: Base32Options) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut len = bytes.len();
+    if options.pad {
+        assert!(len % 8 == 0, "invalid Base32 padding");
+        let mut padding = 0;
+        while padding < 6 && len > 0 && bytes[len - 1] == b'=' {
+            len -= 1;
+            padding += 1;
+        }
+    }
+    assert!(
+        matches!(len % 8, 0 | 2 | 4 | 5 | 7),
+        "invalid Base32 length"
+    );
+
+    let mut buf = buf;
+
+    // Bits that have been read but not yet written.
+    let mut acc = 0u32;
+    let mut bits = 0;
+
+    let mut i = 0;
+    while i < len {
+        acc = (acc << 5) | value(options.alphabet, bytes[i]);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            buf = buf.push_byte((acc >> bits) as u8);
+        }
+        acc &= (1 << bits) - 1;
+        i += 1;
+    }
+    assert!(acc == 0, "invalid Base32 trailing bits");
+    buf
+}
+
+/// Returns the Base32 digit with a value.
+const fn digit(options: Base32Options, value: usize) -> u8 {
+    let digit = options.alphabet[value];
+    if options.lower {
+        digit.to_ascii_lowercase()
+    } else {
+        digit
+    }
+}
+
+/// Returns the value of a Base32 digit, ignoring case.
+const fn value(alphabet: &[u8; 32], digit: u8) -> u32 {
+    let digit = digit.to_ascii_uppercase();
+
+    let mut i = 0;
+    while i < alphabet.len() {
+        if alphabet[i] == digit {
+            return i as u32;
+        }
+        i += 1;
+    }
+    panic!("invalid Base32 character");
+}
@@ -0,0 +1,178 @@
+use crate::buf::Buf;
+
+/// Encodes constant data as Base58, producing a constant `&str`.
+///
+/// The data may be a `&str`, a `&[u8]` or a `&[u8; N]`, and is encoded as a
+/// big-endian number using the Bitcoin alphabet, with each leading zero byte
+/// written as `1`.
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_base58;
+/// const ENCODED: &str = chstr_base58!("Hello World!");
+///
+/// assert_eq!(ENCODED, "2NEpo7TZRRrLZSi2U");
+/// assert_eq!(chstr_base58!(&[0x00, 0x00, 0x28, 0x7F, 0xB4, 0xCD]), "11233QC4");
+/// ```
+#[macro_export]
+macro_rules! chstr_base58 {
+    ($data:expr $(,)?) => {{
+        const BYTES: &[u8] = $crate::__private::Bytes($data).get();
+        const DIGITS: [u8; $crate::__private::base58_digits_len(BYTES.len())] =
+            $crate::__private::base58_digits(BYTES);
+
+        $crate::__chstr_build!(|buf| $crate::__private::base58_encode(buf, BYTES, &DIGITS))
+    }};
+}
+
+/// Decodes a constant Base58 `&str`, producing a constant `&[u8; N]`.
+///
+/// The Bitcoin alphabet is used, with each leading `1` decoded as a zero byte.
+/// Invalid characters are a compile-time error.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_base58_decode;
+/// const DATA: &[u8; 12] = chstr_base58_decode!("2NEpo7TZRRrLZSi2U");
+///
+/// assert_eq!(DATA, b"Hello World!");
+/// assert_eq!(chstr_base58_decode!("11233QC4"), &[0x00, 0x00, 0x28, 0x7F, 0xB4, 0xCD]);
+/// ```
+///
+/// Invalid input fails to compile:
+/// ```compile_fail
+/// # use chstr::chstr_base58_decode;
+/// const BAD: &[u8] = chstr_base58_decode!("0OIl");
+/// ```
+#[macro_export]
+macro_rules! chstr_base58_decode {
+    ($s:expr $(,)?) => {{
+        const STR: &str = $s;
+        const BYTES: [u8; $crate::__private::base58_bytes_len(STR.len())] =
+            $crate::__private::base58_bytes(STR);
+
+        $crate::__chstr_build_bytes!(|buf| $crate::__private::base58_decode(buf, STR, &BYTES))
+    }};
+}
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Returns an upper bound on the number of Base58 digits needed to encode
+/// `len` bytes.
+pub const fn base58_digits_len(len: usize) -> usize {
+    // log(256) / log(58) is approximately 1.366.
+    len * 138 / 100 + 1
+}
+
+/// Converts bytes to Base58 digits, in little-endian order and padded with
+/// zeros to `N` digits.
+pub const fn base58_digits<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut digits = [0; N];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut carry = bytes[i] as u32;
+        let mut k = 0;
+        while k < N {
+            carry += (digits[k] as u32) << 8;
+            digits[k] = (carry % 58) as u8;
+            carry /= 58;
+            k += 1;
+        }
+        i += 1;
+    }
+    digits
+}
+
+/// Writes the Base58 encoding of the bytes to the buffer, given their digits
+/// as returned by [`base58_digits`].
+pub const fn base58_encode<const N: usize>(buf: Buf<N>, bytes: &[u8], digits: &[u8]) -> Buf<N> {
+    let mut buf = buf;
+
+    let mut i = 0;
+    while i < bytes.len() && bytes[i] == 0 {
+        buf = buf.push_byte(ALPHABET[0]);
+        i += 1;
+    }
+
+    let mut end = digits.len();
+    while end > 0 && digits[end - 1] == 0 {
+        end -= 1;
+    }
+    while end > 0 {
+        end -= 1;
+        buf = buf.push_byte(ALPHABET[digits[end] as usize]);
+    }
+    buf
+}
+
+/// Returns an upper bound on the number of bytes encoded by `len` Base58
+/// digits.
+pub const fn base58_bytes_len(len: usize) -> usize {
+    // log(58) / log(256) is approximately 0.732.
+    len * 733 / 1000 + 1
+}
+
+/// Converts a Base58 string to bytes, in little-endian order and padded with
+/// zeros to `N` bytes.
+///
+/// # Panics
+///
+/// Panics if the string is not valid Base58.
+pub const fn base58_bytes<const N: usize>(s: &str) -> [u8; N] {
+    let digits = s.as_bytes();
+    let mut bytes = [0; N];
+
+    let mut i = 0;
+    while i < digits.len() {
+        let mut carry = value(digits[i]);
+        let mut k = 0;
+        while k < N {
+            carry += bytes[k] as u32 * 58;
+            bytes[k] = carry as u8;
+            carry >>= 8;
+            k += 1;
+        }
+        i += 1;
+    }
+    bytes
+}
+
+/// Writes the bytes decoded from a Base58 string to the buffer, given the
+/// bytes as returned by [`base58_bytes`].
+pub const fn base58_decode<const N: usize>(buf: Buf<N>, s: &str, bytes: &[u8]) -> Buf<N> {
+    let digits = s.as_bytes();
+
+    let mut buf = buf;
+
+    let mut i = 0;
+    while i < digits.len() && digits[i] == ALPHABET[0] {
+        buf = buf.push_byte(0);
+        i += 1;
+    }
+
+    let mut end = bytes.len();
+    while end > 0 && bytes[end - 1] == 0 {
+        end -= 1;
+    }
+    while end > 0 {
+        end -= 1;
+        buf = buf.push_byte(bytes[end]);
+    }
+    buf
+}
+
+/// Returns the value of a Base58 digit.
+const fn value(digit: u8) -> u32 {
+    let mut i = 0;
+    while i < ALPHABET.len() {
+        if ALPHABET[i] == digit {
+            return i as u32;
+        }
+        i += 1;
+    }
+    panic!("invalid Base58 character");
+}
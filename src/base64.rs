@@ -0,0 +1,218 @@
+use crate::buf::Buf;
+
+/// Encodes constant data as Base64, producing a constant `&str`.
+///
+/// The data may be a `&str`, a `&[u8]` or a `&[u8; N]`. By default, the
+/// standard alphabet from [RFC 4648] is used, with padding. Options may follow
+/// the data:
+///
+/// - `url_safe` uses the URL and filename safe alphabet, with `-` and `_` in
+///   place of `+` and `/`.
+/// - `no_pad` omits the trailing `=` padding.
+///
+/// [RFC 4648]: https://datatracker.ietf.org/doc/html/rfc4648
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_base64;
+/// const TOKEN: &str = chstr_base64!("chstr?");
+///
+/// assert_eq!(TOKEN, "Y2hzdHI/");
+/// assert_eq!(chstr_base64!(b"\xFB\xFF"), "+/8=");
+/// ```
+///
+/// Options:
+/// ```
+/// # use chstr::chstr_base64;
+/// const DATA: &[u8] = &[0xFB, 0xFF];
+///
+/// assert_eq!(chstr_base64!(DATA, url_safe), "-_8=");
+/// assert_eq!(chstr_base64!(DATA, url_safe, no_pad), "-_8");
+/// ```
+#[macro_export]
+macro_rules! chstr_base64 {
+    ($data:expr $(, $opt:ident)* $(,)?) => {{
+        const BYTES: &[u8] = $crate::__private::Bytes($data).get();
+        const OPTIONS: $crate::__private::Base64Options =
+            $crate::__private::Base64Options::new()$(.$opt())*;
+
+        $crate::__chstr_build!(|buf| $crate::__private::base64_encode(buf, BYTES, OPTIONS))
+    }};
+}
+
+/// Decodes a constant Base64 `&str`, producing a constant `&[u8; N]`.
+///
+/// Accepts the same options as [`chstr_base64!`], which select the alphabet
+/// and whether the input must be padded. Invalid characters, incorrect
+/// padding and non-zero trailing bits are compile-time errors.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_base64_decode;
+/// const DATA: &[u8; 6] = chstr_base64_decode!("Y2hzdHI/");
+///
+/// assert_eq!(DATA, b"chstr?");
+/// assert_eq!(chstr_base64_decode!("-_8", url_safe, no_pad), &[0xFB, 0xFF]);
+/// ```
+///
+/// Invalid input fails to compile:
+/// ```compile_fail
+/// # use chstr::chstr_base64_decode;
+/// const BAD: &[u8] = chstr_base64_decode!("Y2hzdHI");
+/// ```
+#[macro_export]
+macro_rules! chstr_base64_decode {
+    ($s:expr $(, $opt:ident)* $(,)?) => {{
+        const STR: &str = $s;
+        const OPTIONS: $crate::__private::Base64Options =
+            $crate::__private::Base64Options::new()$(.$opt())*;
+
+        $crate::__chstr_build_bytes!(|buf| $crate::__private::base64_decode(buf, STR, OPTIONS))
+    }};
+}
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Options for encoding and decoding Base64.
+#[derive(Clone, Copy)]
+pub struct Base64Options {
+    alphabet: &'static [u8; 64],
+    pad: bool,
+}
+
+impl Base64Options {
+    /// Returns the default options.
+    pub const fn new() -> Base64Options {
+        Base64Options {
+            alphabet: STANDARD,
+            pad: true,
+        }
+    }
+
+    /// Uses the URL and filename safe alphabet.
+    pub const fn url_safe(mut self) -> Base64Options {
+        self.alphabet = URL_SAFE;
+        self
+    }
+
+    /// Omits padding.
+    pub const fn no_pad(mut self) -> Base64Options {
+        self.pad = false;
+        self
+    }
+}
+
+impl Default for Base64Options {
+    fn default() -> Base64Options {
+        Base64Options::new()
+    }
+}
+
+/// Writes the Base64 encoding of the bytes to the buffer.
+pub const fn base64_encode<const N: usize>(
+    buf: Buf<N>,
+    bytes: &[u8],
+    options: Base64Options,
+) -> Buf<N> {
+    let alphabet = options.alphabet;
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let remaining = bytes.len() - i;
+
+        let mut group = (bytes[i] as u32) << 16;
+        if remaining > 1 {
+            group |= (bytes[i + 1] as u32) << 8;
+        }
+        if remaining > 2 {
+            group |= bytes[i + 2] as u32;
+        }
+
+        buf = buf
+            .push_byte(alphabet[(group >> 18) as usize & 0x3F])
+            .push_byte(alphabet[(group >> 12) as usize & 0x3F]);
+        if remaining > 1 {
+            buf = buf.push_byte(alphabet[(group >> 6) as usize & 0x3F]);
+        } else if options.pad {
+            buf = buf.push_byte(b'=');
+        }
+        if remaining > 2 {
+            buf = buf.push_byte(alphabet[group as usize & 0x3F]);
+        } else if options.pad {
+            buf = buf.push_byte(b'=');
+        }
+
+        i += 3;
+    }
+    buf
+}
+
+/// Writes the bytes decoded from a Base64 string to the buffer.
+///
+/// # Panics
+///
+/// Panics if the string is not valid Base64.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+pub const fn base64_decode<const N: usize>(buf: Buf<N>, s: &str, options: Base64Options) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut len = bytes.len();
+    if options.pad {
+        assert!(len % 4 == 0, "invalid Base64 padding");
+        let mut padding = 0;
+        while padding < 2 && len > 0 && bytes[len - 1] == b'=' {
+            len -= 1;
+            padding += 1;
+        }
+    }
+    assert!(len % 4 != 1, "invalid Base64 length");
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < len {
+        let remaining = len - i;
+
+        let mut group = 0;
+        let mut k = 0;
+        while k < 4 {
+            group <<= 6;
+            if k < remaining {
+                group |= value(options.alphabet, bytes[i + k]);
+            }
+            k += 1;
+        }
+
+        buf = buf.push_byte((group >> 16) as u8);
+        if remaining > 2 {
+            buf = buf.push_byte((group >> 8) as u8);
+        } else {
+            assert!(group & 0xFFFF == 0, "invalid Base64 trailing bits");
+        }
+        if remaining > 3 {
+            buf = buf.push_byte(group as u8);
+        } else {
+            assert!(group & 0xFF == 0, "invalid Base64 trailing bits");
+        }
+
+        i += 4;
+    }
+    buf
+}
+
+/// Returns the value of a Base64 digit.
+const fn value(alphabet: &[u8; 64], digit: u8) -> u32 {
+    let mut i = 0;
+    while i < alphabet.len() {
+        if alphabet[i] == digit {
+            return i as u32;
+        }
+        i += 1;
+    }
+    panic!("invalid Base64 character");
+}
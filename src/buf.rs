@@ -0,0 +1,94 @@
+use crate::utf8;
+
+/// A fixed-capacity byte buffer for building strings in a constant context.
+///
+/// Strings are built by running the same code twice: first with a `Buf<0>`,
+/// which discards the bytes and only counts them, and then with a `Buf<LEN>`
+/// sized by the first pass.
+pub struct Buf<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Buf<N> {
+    /// Creates an empty buffer.
+    pub const fn new() -> Buf<N> {
+        Buf {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of bytes written to the buffer.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes have been written to the buffer.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a byte to the buffer.
+    pub const fn push_byte(mut self, b: u8) -> Buf<N> {
+        if self.len < N {
+            self.bytes[self.len] = b;
+        }
+        self.len += 1;
+        self
+    }
+
+    /// Appends the UTF-8 encoding of a character to the buffer.
+    pub const fn push_char(mut self, c: char) -> Buf<N> {
+        let (bytes, len) = utf8::encode(c);
+
+        let mut i = 0;
+        while i < len {
+            if self.len < N {
+                self.bytes[self.len] = bytes[i];
+            }
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends a string slice to the buffer.
+    pub const fn push_str(self, s: &str) -> Buf<N> {
+        self.push_slice(s, 0, s.len())
+    }
+
+    /// Appends the byte range `start..end` of a string slice to the buffer.
+    ///
+    /// Both ends of the range must lie on character boundaries.
+    pub const fn push_slice(mut self, s: &str, start: usize, end: usize) -> Buf<N> {
+        let bytes = s.as_bytes();
+
+        let mut i = start;
+        while i < end {
+            if self.len < N {
+                self.bytes[self.len] = bytes[i];
+            }
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the contents of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bytes written does not match the capacity of
+    /// the buffer.
+    pub const fn into_array(self) -> [u8; N] {
+        assert!(self.len == N, "buffer length does not match its capacity");
+        self.bytes
+    }
+}
+
+impl<const N: usize> Default for Buf<N> {
+    fn default() -> Buf<N> {
+        Buf::new()
+    }
+}
@@ -0,0 +1,25 @@
+/// A constant argument converted to a byte slice.
+///
+/// Implemented for `&str`, `&[u8]` and `&[u8; N]`.
+pub struct Bytes<T>(pub T);
+
+impl<'a> Bytes<&'a str> {
+    /// Returns the UTF-8 bytes of the string.
+    pub const fn get(self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<'a> Bytes<&'a [u8]> {
+    /// Returns the byte slice.
+    pub const fn get(self) -> &'a [u8] {
+        self.0
+    }
+}
+
+impl<'a, const N: usize> Bytes<&'a [u8; N]> {
+    /// Returns the byte array as a slice.
+    pub const fn get(self) -> &'a [u8] {
+        self.0
+    }
+}
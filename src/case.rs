@@ -0,0 +1,407 @@
+use crate::buf::Buf;
+use crate::tables::{self, case as table};
+use crate::utf8;
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// uppercase.
+///
+/// Characters are mapped using the full Unicode case mappings, in the same way
+/// as [`str::to_uppercase`], so the result may be longer than the input. The
+/// mappings are taken from Unicode 14.0, and may differ from those of the
+/// current standard library for characters added in later versions.
+///
+/// [`str::to_uppercase`]: https://doc.rust-lang.org/std/primitive.str.html#method.to_uppercase
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_upper;
+/// const APP: &str = "myapp";
+/// const LOG_LEVEL: &str = chstr_upper![APP, '_', "log_level"];
+///
+/// assert_eq!(LOG_LEVEL, "MYAPP_LOG_LEVEL");
+/// ```
+///
+/// Multi-character mappings:
+/// ```
+/// # use chstr::chstr_upper;
+/// const STREET: &str = chstr_upper!["Straße"];
+///
+/// assert_eq!(STREET, "STRASSE");
+/// assert_eq!(STREET, "Straße".to_uppercase());
+/// ```
+#[macro_export]
+macro_rules! chstr_upper {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_uppercase(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// lowercase.
+///
+/// Characters are mapped using the full Unicode case mappings, in the same way
+/// as [`str::to_lowercase`], including the mapping of `'Σ'` to `'ς'` at the end
+/// of a word. The mappings are taken from Unicode 14.0, and may differ from
+/// those of the current standard library for characters added in later
+/// versions.
+///
+/// [`str::to_lowercase`]: https://doc.rust-lang.org/std/primitive.str.html#method.to_lowercase
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_lower;
+/// const HEADER: &str = "Content-Type";
+/// const NAME: &str = chstr_lower![HEADER];
+///
+/// assert_eq!(NAME, "content-type");
+/// ```
+///
+/// Final sigma:
+/// ```
+/// # use chstr::chstr_lower;
+/// const ODYSSEUS: &str = chstr_lower!["ὈΔΥΣΣΕΎΣ"];
+///
+/// assert_eq!(ODYSSEUS, "ὀδυσσεύς");
+/// assert_eq!(ODYSSEUS, "ὈΔΥΣΣΕΎΣ".to_lowercase());
+/// ```
+#[macro_export]
+macro_rules! chstr_lower {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_lowercase(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// uppercase, using simple case mappings.
+///
+/// Unlike [`chstr_upper!`], each character is mapped to exactly one character,
+/// so characters such as `'ß'` are left unchanged.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_simple_upper;
+/// const STREET: &str = chstr_simple_upper!["Straße"];
+///
+/// assert_eq!(STREET, "STRAßE");
+/// ```
+#[macro_export]
+macro_rules! chstr_simple_upper {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_simple_uppercase(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// lowercase, using simple case mappings.
+///
+/// Unlike [`chstr_lower!`], each character is mapped to exactly one character,
+/// and `'Σ'` is always mapped to `'σ'`.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_simple_lower;
+/// const CITY: &str = chstr_simple_lower!["İSTANBUL"];
+///
+/// assert_eq!(CITY, "istanbul");
+/// assert_eq!(CITY.chars().count(), 8);
+/// ```
+#[macro_export]
+macro_rules! chstr_simple_lower {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_simple_lowercase(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` with
+/// ASCII letters in uppercase.
+///
+/// Non-ASCII characters are left unchanged.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_ascii_upper;
+/// const PREFIX: &str = "myapp";
+/// const VAR: &str = chstr_ascii_upper![PREFIX, "_straße"];
+///
+/// assert_eq!(VAR, "MYAPP_STRAßE");
+/// ```
+#[macro_export]
+macro_rules! chstr_ascii_upper {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_ascii_uppercase(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` with
+/// ASCII letters in lowercase.
+///
+/// Non-ASCII characters are left unchanged.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_ascii_lower;
+/// const HEADER: &str = chstr_ascii_lower!["X-Request-ID", 'Ä'];
+///
+/// assert_eq!(HEADER, "x-request-idÄ");
+/// ```
+#[macro_export]
+macro_rules! chstr_ascii_lower {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_ascii_lowercase(buf, STR))
+    }};
+}
+
+/// Writes a string to the buffer in uppercase, using full case mappings.
+pub const fn to_uppercase<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    write_uppercase(buf, s.as_bytes(), 0, s.len())
+}
+
+/// Writes a string to the buffer in lowercase, using full case mappings.
+pub const fn to_lowercase<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    write_lowercase(buf, s.as_bytes(), 0, s.len())
+}
+
+/// Writes a string to the buffer in uppercase, using simple case mappings.
+pub const fn to_simple_uppercase<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = buf.push_char(simple_mapping(c, table::UPPERCASE, table::SIMPLE_UPPERCASE));
+        i += len;
+    }
+    buf
+}
+
+/// Writes a string to the buffer in lowercase, using simple case mappings.
+pub const fn to_simple_lowercase<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = buf.push_char(simple_mapping(c, table::LOWERCASE, table::SIMPLE_LOWERCASE));
+        i += len;
+    }
+    buf
+}
+
+/// Writes a string to the buffer with ASCII letters in uppercase.
+pub const fn to_ascii_uppercase<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        buf = buf.push_byte(bytes[i].to_ascii_uppercase());
+        i += 1;
+    }
+    buf
+}
+
+/// Writes a string to the buffer with ASCII letters in lowercase.
+pub const fn to_ascii_lowercase<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        buf = buf.push_byte(bytes[i].to_ascii_lowercase());
+        i += 1;
+    }
+    buf
+}
+
+/// Writes the full case mapping of a character from a table to the buffer.
+const fn push_mapping<const N: usize>(buf: Buf<N>, c: char, table: &[(char, [char; 3])]) -> Buf<N> {
+    let mapping = match tables::find(table, c) {
+        Some(i) => table[i].1,
+        None => return buf.push_char(c),
+    };
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < mapping.len() && mapping[i] != '\0' {
+        buf = buf.push_char(mapping[i]);
+        i += 1;
+    }
+    buf
+}
+
+/// Returns the simple case mapping of a character.
+const fn simple_mapping(c: char, full: &[(char, [char; 3])], simple: &[(char, char)]) -> char {
+    if let Some(i) = tables::find(simple, c) {
+        return simple[i].1;
+    }
+    match tables::find(full, c) {
+        Some(i) => full[i].1[0],
+        None => c,
+    }
+}
+
+/// Writes the byte range `start..end` of a string to the buffer in uppercase,
+/// using full case mappings.
+pub(crate) const fn write_uppercase<const N: usize>(
+    buf: Buf<N>,
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Buf<N> {
+    let mut buf = buf;
+    let mut i = start;
+    while i < end {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = push_mapping(buf, c, table::UPPERCASE);
+        i += len;
+    }
+    buf
+}
+
+/// Writes the byte range `start..end` of a string to the buffer in lowercase,
+/// using full case mappings.
+///
+/// Only the characters within the range are considered when mapping a final
+/// sigma.
+pub(crate) const fn write_lowercase<const N: usize>(
+    buf: Buf<N>,
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Buf<N> {
+    lowercase_from(buf, bytes, start, end, start)
+}
+
+/// Writes the byte range `start..end` of a string to the buffer with the first
+/// character in titlecase and the rest in lowercase, using full case mappings.
+///
+/// Only the characters within the range are considered when mapping a final
+/// sigma.
+pub(crate) const fn write_titlecase<const N: usize>(
+    buf: Buf<N>,
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Buf<N> {
+    if start == end {
+        return buf;
+    }
+
+    let (c, len) = utf8::decode(bytes, start);
+    let buf = if tables::find(table::TITLECASE, c).is_some() {
+        push_mapping(buf, c, table::TITLECASE)
+    } else {
+        push_mapping(buf, c, table::UPPERCASE)
+    };
+    lowercase_from(buf, bytes, start, end, start + len)
+}
+
+/// Writes the byte range `from..end` of a string to the buffer in lowercase,
+/// using full case mappings.
+///
+/// The characters in the byte range `start..end` are considered when mapping
+/// a final sigma.
+const fn lowercase_from<const N: usize>(
+    buf: Buf<N>,
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    from: usize,
+) -> Buf<N> {
+    let mut buf = buf;
+    let mut i = from;
+    while i < end {
+        let (c, len) = utf8::decode(bytes, i);
+        if c == 'Σ' {
+            let final_sigma = is_word_final(bytes, start, end, i, i + len);
+            buf = buf.push_char(if final_sigma { 'ς' } else { 'σ' });
+        } else {
+            buf = push_mapping(buf, c, table::LOWERCASE);
+        }
+        i += len;
+    }
+    buf
+}
+
+/// Returns `true` if a character is uppercase, meaning that it has a lowercase
+/// mapping.
+pub(crate) const fn is_uppercase(c: char) -> bool {
+    tables::find(table::LOWERCASE, c).is_some()
+}
+
+/// Returns `true` if a character is lowercase, meaning that it has an
+/// uppercase mapping.
+pub(crate) const fn is_lowercase(c: char) -> bool {
+    tables::find(table::UPPERCASE, c).is_some()
+}
+
+/// Returns `true` if the character in the byte range `c_start..c_end` of a
+/// string is at the end of a word, for the purposes of mapping a final sigma.
+///
+/// Only the characters in the byte range `start..end` are considered.
+const fn is_word_final(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    c_start: usize,
+    c_end: usize,
+) -> bool {
+    let mut i = c_start;
+    loop {
+        if i == start {
+            return false;
+        }
+        let (c, len) = utf8::decode_last(bytes, i);
+        i -= len;
+
+        if !tables::in_ranges(table::CASE_IGNORABLE, c) {
+            if !tables::in_ranges(table::CASED, c) {
+                return false;
+            }
+            break;
+        }
+    }
+
+    let mut i = c_end;
+    while i < end {
+        let (c, len) = utf8::decode(bytes, i);
+        i += len;
+
+        if !tables::in_ranges(table::CASE_IGNORABLE, c) {
+            return !tables::in_ranges(table::CASED, c);
+        }
+    }
+    true
+}
@@ -0,0 +1,159 @@
+use crate::buf::Buf;
+use crate::int::{self, Spec};
+use crate::tables::{self, escape as table};
+use crate::utf8;
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str`, escaped
+/// as by [`str::escape_debug`].
+///
+/// Quotes, backslashes and the `\0`, `\t`, `\r` and `\n` control characters
+/// are escaped with a backslash. Other characters that are not printable, and
+/// a grapheme extending character at the start of the string, are escaped as
+/// `\u{NNNN}`. Wrapping the result in double quotes produces a Rust string
+/// literal.
+///
+/// Printable and grapheme extending characters are determined from Unicode
+/// 14.0 tables, so the result may differ from that of the current standard
+/// library for characters added in later versions.
+///
+/// [`str::escape_debug`]: https://doc.rust-lang.org/std/primitive.str.html#method.escape_debug
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_escape_debug;
+/// const MESSAGE: &str = "\"Hé\"\n\u{200B}";
+/// const ESCAPED: &str = chstr_escape_debug![MESSAGE];
+///
+/// assert_eq!(ESCAPED, r#"\"Hé\"\n\u{200b}"#);
+/// assert_eq!(ESCAPED, MESSAGE.escape_debug().to_string());
+/// ```
+#[macro_export]
+macro_rules! chstr_escape_debug {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::escape_debug(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str`, escaped
+/// as by [`str::escape_default`].
+///
+/// Quotes, backslashes and the `\t`, `\r` and `\n` control characters are
+/// escaped with a backslash, and all other characters outside of the printable
+/// ASCII range are escaped as `\u{NNNN}`.
+///
+/// [`str::escape_default`]: https://doc.rust-lang.org/std/primitive.str.html#method.escape_default
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_escape_default;
+/// const MESSAGE: &str = "\"Hé\"\n";
+/// const ESCAPED: &str = chstr_escape_default![MESSAGE];
+///
+/// assert_eq!(ESCAPED, r#"\"H\u{e9}\"\n"#);
+/// assert_eq!(ESCAPED, MESSAGE.escape_default().to_string());
+/// ```
+#[macro_export]
+macro_rules! chstr_escape_default {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::escape_default(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str`, with
+/// every character escaped as `\u{NNNN}`, as by [`str::escape_unicode`].
+///
+/// [`str::escape_unicode`]: https://doc.rust-lang.org/std/primitive.str.html#method.escape_unicode
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_escape_unicode;
+/// const ESCAPED: &str = chstr_escape_unicode!['a', "é\n"];
+///
+/// assert_eq!(ESCAPED, r"\u{61}\u{e9}\u{a}");
+/// assert_eq!(ESCAPED, "aé\n".escape_unicode().to_string());
+/// ```
+#[macro_export]
+macro_rules! chstr_escape_unicode {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::escape_unicode(buf, STR))
+    }};
+}
+
+/// Writes a string to the buffer, escaped as by `str::escape_debug`.
+pub const fn escape_debug<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = match c {
+            '\0' => buf.push_str("\\0"),
+            '\t' => buf.push_str("\\t"),
+            '\r' => buf.push_str("\\r"),
+            '\n' => buf.push_str("\\n"),
+            '\'' | '"' | '\\' => buf.push_byte(b'\\').push_char(c),
+            _ if i == 0 && tables::in_ranges(table::GRAPHEME_EXTEND, c) => {
+                push_unicode_escape(buf, c)
+            }
+            _ if tables::in_ranges(table::NOT_PRINTABLE, c) => push_unicode_escape(buf, c),
+            _ => buf.push_char(c),
+        };
+        i += len;
+    }
+    buf
+}
+
+/// Writes a string to the buffer, escaped as by `str::escape_default`.
+pub const fn escape_default<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = match c {
+            '\t' => buf.push_str("\\t"),
+            '\r' => buf.push_str("\\r"),
+            '\n' => buf.push_str("\\n"),
+            '\'' | '"' | '\\' => buf.push_byte(b'\\').push_char(c),
+            '\x20'..='\x7e' => buf.push_char(c),
+            _ => push_unicode_escape(buf, c),
+        };
+        i += len;
+    }
+    buf
+}
+
+/// Writes a string to the buffer, escaped as by `str::escape_unicode`.
+pub const fn escape_unicode<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = push_unicode_escape(buf, c);
+        i += len;
+    }
+    buf
+}
+
+/// Writes a character to the buffer as a `\u{NNNN}` escape.
+pub(crate) const fn push_unicode_escape<const N: usize>(buf: Buf<N>, c: char) -> Buf<N> {
+    let buf = buf.push_str("\\u{");
+    let buf = int::write(buf, false, c as u128, Spec::LOWER_HEX);
+    buf.push_byte(b'}')
+}
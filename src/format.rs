@@ -0,0 +1,46 @@
+use crate::buf::Buf;
+
+/// Writes a format string to the buffer, replacing each `{}` placeholder with
+/// the next argument.
+///
+/// Braces are escaped by doubling them, as `{{` and `}}`.
+pub const fn format<const N: usize>(buf: Buf<N>, fmt: &str, args: &[&str]) -> Buf<N> {
+    let bytes = fmt.as_bytes();
+
+    let mut buf = buf;
+    let mut arg = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' if i + 1 < bytes.len() && bytes[i + 1] == b'{' => {
+                buf = buf.push_byte(b'{');
+                i += 2;
+            }
+            b'{' if i + 1 < bytes.len() && bytes[i + 1] == b'}' => {
+                if arg == args.len() {
+                    panic!("format string has more placeholders than arguments");
+                }
+                buf = buf.push_str(args[arg]);
+                arg += 1;
+                i += 2;
+            }
+            b'{' => panic!("invalid format string: expected `}}` after `{{`"),
+            b'}' if i + 1 < bytes.len() && bytes[i + 1] == b'}' => {
+                buf = buf.push_byte(b'}');
+                i += 2;
+            }
+            b'}' => panic!("invalid format string: unmatched `}}` found"),
+            b => {
+                buf = buf.push_byte(b);
+                i += 1;
+            }
+        }
+    }
+
+    if arg != args.len() {
+        panic!("format string has fewer placeholders than arguments");
+    }
+
+    buf
+}
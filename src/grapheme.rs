@@ -0,0 +1,94 @@
+//! Extended grapheme cluster segmentation, as defined by
+//! [UAX #29](https://www.unicode.org/reports/tr29/).
+
+use crate::tables::{self, grapheme as table};
+use crate::utf8;
+
+/// The `Grapheme_Cluster_Break` property of a character.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy)]
+pub(crate) enum GraphemeClusterBreak {
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    Other,
+}
+
+use GraphemeClusterBreak::*;
+
+/// Returns the `Grapheme_Cluster_Break` property of a character.
+const fn category(c: char) -> GraphemeClusterBreak {
+    match tables::find_range(table::GRAPHEME_CLUSTER_BREAK, c) {
+        Some(i) => table::GRAPHEME_CLUSTER_BREAK[i].2,
+        None => Other,
+    }
+}
+
+/// Returns `true` if a character has the `Extended_Pictographic` property.
+const fn is_extended_pictographic(c: char) -> bool {
+    tables::in_ranges(table::EXTENDED_PICTOGRAPHIC, c)
+}
+
+/// Returns the byte index of the end of the grapheme cluster starting at byte
+/// `start` of a string.
+pub(crate) const fn next_boundary(bytes: &[u8], start: usize) -> usize {
+    let (c, len) = utf8::decode(bytes, start);
+    let mut prev = category(c);
+    let mut i = start + len;
+
+    // Whether the cluster so far ends with `Extended_Pictographic Extend*`.
+    let mut pictographic = is_extended_pictographic(c);
+    // Whether the cluster so far ends with `Extended_Pictographic Extend* ZWJ`.
+    let mut pictographic_zwj = false;
+    // The number of consecutive regional indicators at the end of the cluster.
+    let mut regional_indicators = matches!(prev, RegionalIndicator) as usize;
+
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        let next = category(c);
+
+        let joined = match (prev, next) {
+            // GB3
+            (CR, LF) => true,
+            // GB4, GB5
+            (Control | CR | LF, _) | (_, Control | CR | LF) => false,
+            // GB6, GB7, GB8
+            (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => true,
+            // GB9, GB9a
+            (_, Extend | ZWJ | SpacingMark) => true,
+            // GB9b
+            (Prepend, _) => true,
+            // GB11
+            (ZWJ, _) => pictographic_zwj && is_extended_pictographic(c),
+            // GB12, GB13
+            (RegionalIndicator, RegionalIndicator) => regional_indicators % 2 == 1,
+            // GB999
+            _ => false,
+        };
+        if !joined {
+            break;
+        }
+
+        pictographic_zwj = pictographic && matches!(next, ZWJ);
+        pictographic = is_extended_pictographic(c) || (pictographic && matches!(next, Extend));
+        regional_indicators = match next {
+            RegionalIndicator => regional_indicators + 1,
+            _ => 0,
+        };
+
+        prev = next;
+        i += len;
+    }
+
+    i
+}
@@ -0,0 +1,519 @@
+//! Constant hash functions.
+//!
+//! Each function hashes a byte slice, with the same result as writing the
+//! bytes to the corresponding [`Hasher`] with a single call to `write` and
+//! then calling `finish`. Note that this differs from hashing a `&str` with
+//! [`Hash`], which also writes a terminating `0xFF` byte.
+//!
+//! Strings can be hashed with [`str::as_bytes`], and [`chstr_hash!`] builds a
+//! string from a sequence of [`chstr!`] arguments along with its hash.
+//!
+//! [`Hasher`]: https://doc.rust-lang.org/std/hash/trait.Hasher.html
+//! [`Hash`]: https://doc.rust-lang.org/std/hash/trait.Hash.html
+//! [`str::as_bytes`]: https://doc.rust-lang.org/std/primitive.str.html#method.as_bytes
+//! [`chstr_hash!`]: crate::chstr_hash
+//! [`chstr!`]: crate::chstr
+//!
+//! # Examples
+//!
+//! ```
+//! use chstr::hash;
+//!
+//! const ID: u64 = hash::fnv1a_64(b"config");
+//!
+//! match hash::fnv1a_64("config".as_bytes()) {
+//!     ID => {}
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+/// Builds a constant `&str` from a sequence of [`chstr!`] arguments, returning
+/// it along with its hash.
+///
+/// The first argument names a function in the [`hash`](crate::hash) module,
+/// followed by any arguments after the bytes in parentheses, and a `;`.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_hash;
+/// const PREFIX: &str = "app";
+/// const KEY: (&str, u64) = chstr_hash!(fnv1a_64; PREFIX, '.', "log_level");
+///
+/// assert_eq!(KEY, ("app.log_level", chstr::hash::fnv1a_64(b"app.log_level")));
+/// ```
+///
+/// Functions with arguments:
+/// ```
+/// # use chstr::chstr_hash;
+/// const SEEDED: (&str, u64) = chstr_hash!(xxh64(7); "key");
+/// const KEYED: (&str, u64) = chstr_hash!(sip13(1, 2); "key");
+/// const SMALL: (&str, u32) = chstr_hash!(fnv1a_32; "key");
+///
+/// assert_eq!(SEEDED.1, chstr::hash::xxh64(b"key", 7));
+/// assert_eq!(KEYED.1, chstr::hash::sip13(b"key", 1, 2));
+/// assert_eq!(SMALL.1, chstr::hash::fnv1a_32(b"key"));
+/// ```
+#[macro_export]
+macro_rules! chstr_hash {
+    ($hash:ident $(($($param:expr),* $(,)?))?; $($arg:expr),* $(,)?) => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+        const HASH: $crate::hash::output::$hash =
+            $crate::hash::$hash(STR.as_bytes() $($(, $param)*)?);
+
+        (STR, HASH)
+    }};
+}
+
+/// The output type of each hash function, named after the function, so that
+/// [`chstr_hash!`](crate::chstr_hash) can declare the hash as a constant.
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub mod output {
+    pub type fnv1a_32 = u32;
+    pub type fnv1a_64 = u64;
+    pub type fx_hash = u64;
+    pub type xxh64 = u64;
+    pub type xxh3_64 = u64;
+    pub type sip13 = u64;
+}
+
+/// Reads a little-endian integer of `len` bytes starting at index `i`.
+const fn read(bytes: &[u8], i: usize, len: usize) -> u64 {
+    let mut value = 0;
+    let mut k = len;
+    while k > 0 {
+        k -= 1;
+        value = (value << 8) | bytes[i + k] as u64;
+    }
+    value
+}
+
+/// Computes the 32-bit [FNV-1a] hash of the bytes.
+///
+/// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(chstr::hash::fnv1a_32(b""), 0x811C_9DC5);
+/// assert_eq!(chstr::hash::fnv1a_32(b"foobar"), 0xBF9C_F968);
+/// ```
+pub const fn fnv1a_32(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811C_9DC5;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes the 64-bit [FNV-1a] hash of the bytes.
+///
+/// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(chstr::hash::fnv1a_64(b""), 0xCBF2_9CE4_8422_2325);
+/// assert_eq!(chstr::hash::fnv1a_64(b"foobar"), 0x8594_4171_F739_67E8);
+/// ```
+pub const fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+        i += 1;
+    }
+    hash
+}
+
+/// Computes the 64-bit FxHash of the bytes, as used by `rustc`.
+///
+/// This matches the `FxHasher` from version 1 of the `rustc-hash` crate on
+/// little-endian 64-bit targets.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(chstr::hash::fx_hash(b""), 0);
+/// assert_eq!(chstr::hash::fx_hash(b"foobar"), 0x41F8_CDE2_B60C_9DC4);
+/// ```
+pub const fn fx_hash(bytes: &[u8]) -> u64 {
+    const fn add(hash: u64, word: u64) -> u64 {
+        (hash.rotate_left(5) ^ word).wrapping_mul(0x517C_C1B7_2722_0A95)
+    }
+
+    let mut hash = 0;
+    let mut i = 0;
+    while bytes.len() - i >= 8 {
+        hash = add(hash, read(bytes, i, 8));
+        i += 8;
+    }
+    if bytes.len() - i >= 4 {
+        hash = add(hash, read(bytes, i, 4));
+        i += 4;
+    }
+    if bytes.len() - i >= 2 {
+        hash = add(hash, read(bytes, i, 2));
+        i += 2;
+    }
+    if bytes.len() - i >= 1 {
+        hash = add(hash, bytes[i] as u64);
+    }
+    hash
+}
+
+const XXH_PRIME32_1: u64 = 0x9E37_79B1;
+const XXH_PRIME32_2: u64 = 0x85EB_CA77;
+const XXH_PRIME32_3: u64 = 0xC2B2_AE3D;
+const XXH_PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const XXH_PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const XXH_PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const XXH_PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const XXH_PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+/// Computes the [xxHash64] hash of the bytes with a seed.
+///
+/// [xxHash64]: https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(chstr::hash::xxh64(b"", 0), 0xEF46_DB37_51D8_E999);
+/// assert_eq!(chstr::hash::xxh64(b"foobar", 0), 0xA2AA_05ED_9085_AAF9);
+/// assert_eq!(chstr::hash::xxh64(b"foobar", 1), 0xF832_30D7_0D4C_A00E);
+/// ```
+pub const fn xxh64(bytes: &[u8], seed: u64) -> u64 {
+    const fn round(acc: u64, input: u64) -> u64 {
+        acc.wrapping_add(input.wrapping_mul(XXH_PRIME64_2))
+            .rotate_left(31)
+            .wrapping_mul(XXH_PRIME64_1)
+    }
+
+    const fn merge_round(acc: u64, value: u64) -> u64 {
+        (acc ^ round(0, value))
+            .wrapping_mul(XXH_PRIME64_1)
+            .wrapping_add(XXH_PRIME64_4)
+    }
+
+    let len = bytes.len();
+    let mut i = 0;
+
+    let mut hash = if len >= 32 {
+        let mut v1 = seed.wrapping_add(XXH_PRIME64_1).wrapping_add(XXH_PRIME64_2);
+        let mut v2 = seed.wrapping_add(XXH_PRIME64_2);
+        let mut v3 = seed;
+        let mut v4 = seed.wrapping_sub(XXH_PRIME64_1);
+
+        while len - i >= 32 {
+            v1 = round(v1, read(bytes, i, 8));
+            v2 = round(v2, read(bytes, i + 8, 8));
+            v3 = round(v3, read(bytes, i + 16, 8));
+            v4 = round(v4, read(bytes, i + 24, 8));
+            i += 32;
+        }
+
+        let hash = v1
+            .rotate_left(1)
+            .wrapping_add(v2.rotate_left(7))
+            .wrapping_add(v3.rotate_left(12))
+            .wrapping_add(v4.rotate_left(18));
+        let hash = merge_round(hash, v1);
+        let hash = merge_round(hash, v2);
+        let hash = merge_round(hash, v3);
+        merge_round(hash, v4)
+    } else {
+        seed.wrapping_add(XXH_PRIME64_5)
+    };
+    hash = hash.wrapping_add(len as u64);
+
+    while len - i >= 8 {
+        hash ^= round(0, read(bytes, i, 8));
+        hash = hash
+            .rotate_left(27)
+            .wrapping_mul(XXH_PRIME64_1)
+            .wrapping_add(XXH_PRIME64_4);
+        i += 8;
+    }
+    if len - i >= 4 {
+        hash ^= read(bytes, i, 4).wrapping_mul(XXH_PRIME64_1);
+        hash = hash
+            .rotate_left(23)
+            .wrapping_mul(XXH_PRIME64_2)
+            .wrapping_add(XXH_PRIME64_3);
+        i += 4;
+    }
+    while i < len {
+        hash ^= (bytes[i] as u64).wrapping_mul(XXH_PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(XXH_PRIME64_1);
+        i += 1;
+    }
+
+    xxh64_avalanche(hash)
+}
+
+/// Mixes the bits of an xxHash64 hash.
+const fn xxh64_avalanche(hash: u64) -> u64 {
+    let hash = (hash ^ (hash >> 33)).wrapping_mul(XXH_PRIME64_2);
+    let hash = (hash ^ (hash >> 29)).wrapping_mul(XXH_PRIME64_3);
+    hash ^ (hash >> 32)
+}
+
+/// The default secret used by XXH3.
+#[rustfmt::skip]
+const XXH3_SECRET: &[u8; 192] = &[
+    0xB8, 0xFE, 0x6C, 0x39, 0x23, 0xA4, 0x4B, 0xBE, 0x7C, 0x01, 0x81, 0x2C, 0xF7, 0x21, 0xAD, 0x1C,
+    0xDE, 0xD4, 0x6D, 0xE9, 0x83, 0x90, 0x97, 0xDB, 0x72, 0x40, 0xA4, 0xA4, 0xB7, 0xB3, 0x67, 0x1F,
+    0xCB, 0x79, 0xE6, 0x4E, 0xCC, 0xC0, 0xE5, 0x78, 0x82, 0x5A, 0xD0, 0x7D, 0xCC, 0xFF, 0x72, 0x21,
+    0xB8, 0x08, 0x46, 0x74, 0xF7, 0x43, 0x24, 0x8E, 0xE0, 0x35, 0x90, 0xE6, 0x81, 0x3A, 0x26, 0x4C,
+    0x3C, 0x28, 0x52, 0xBB, 0x91, 0xC3, 0x00, 0xCB, 0x88, 0xD0, 0x65, 0x8B, 0x1B, 0x53, 0x2E, 0xA3,
+    0x71, 0x64, 0x48, 0x97, 0xA2, 0x0D, 0xF9, 0x4E, 0x38, 0x19, 0xEF, 0x46, 0xA9, 0xDE, 0xAC, 0xD8,
+    0xA8, 0xFA, 0x76, 0x3F, 0xE3, 0x9C, 0x34, 0x3F, 0xF9, 0xDC, 0xBB, 0xC7, 0xC7, 0x0B, 0x4F, 0x1D,
+    0x8A, 0x51, 0xE0, 0x4B, 0xCD, 0xB4, 0x59, 0x31, 0xC8, 0x9F, 0x7E, 0xC9, 0xD9, 0x78, 0x73, 0x64,
+    0xEA, 0xC5, 0xAC, 0x83, 0x34, 0xD3, 0xEB, 0xC3, 0xC5, 0x81, 0xA0, 0xFF, 0xFA, 0x13, 0x63, 0xEB,
+    0x17, 0x0D, 0xDD, 0x51, 0xB7, 0xF0, 0xDA, 0x49, 0xD3, 0x16, 0x55, 0x26, 0x29, 0xD4, 0x68, 0x9E,
+    0x2B, 0x16, 0xBE, 0x58, 0x7D, 0x47, 0xA1, 0xFC, 0x8F, 0xF8, 0xB8, 0xD1, 0x7A, 0xD0, 0x31, 0xCE,
+    0x45, 0xCB, 0x3A, 0x8F, 0x95, 0x16, 0x04, 0x28, 0xAF, 0xD7, 0xFB, 0xCA, 0xBB, 0x4B, 0x40, 0x7E,
+];
+
+/// Computes the 64-bit [XXH3] hash of the bytes, with the default secret and a
+/// seed of zero.
+///
+/// [XXH3]: https://github.com/Cyan4973/xxHash
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(chstr::hash::xxh3_64(b""), 0x2D06_8005_38D3_94C2);
+/// assert_eq!(chstr::hash::xxh3_64(b"foobar"), 0xD78F_DA63_144C_5C84);
+/// ```
+pub const fn xxh3_64(bytes: &[u8]) -> u64 {
+    let len = bytes.len();
+    let secret = XXH3_SECRET;
+
+    if len == 0 {
+        xxh64_avalanche(read(secret, 56, 8) ^ read(secret, 64, 8))
+    } else if len <= 3 {
+        let combo = ((bytes[0] as u64) << 16)
+            | ((bytes[len >> 1] as u64) << 24)
+            | (bytes[len - 1] as u64)
+            | ((len as u64) << 8);
+        xxh64_avalanche(combo ^ (read(secret, 0, 4) ^ read(secret, 4, 4)))
+    } else if len <= 8 {
+        let input = read(bytes, len - 4, 4).wrapping_add(read(bytes, 0, 4) << 32);
+        let keyed = input ^ (read(secret, 8, 8) ^ read(secret, 16, 8));
+
+        let mut hash = keyed ^ keyed.rotate_left(49) ^ keyed.rotate_left(24);
+        hash = hash.wrapping_mul(0x9FB2_1C65_1E98_DF25);
+        hash ^= (hash >> 35).wrapping_add(len as u64);
+        hash = hash.wrapping_mul(0x9FB2_1C65_1E98_DF25);
+        hash ^ (hash >> 28)
+    } else if len <= 16 {
+        let lo = read(bytes, 0, 8) ^ (read(secret, 24, 8) ^ read(secret, 32, 8));
+        let hi = read(bytes, len - 8, 8) ^ (read(secret, 40, 8) ^ read(secret, 48, 8));
+        let hash = (len as u64)
+            .wrapping_add(lo.swap_bytes())
+            .wrapping_add(hi)
+            .wrapping_add(mul128_fold64(lo, hi));
+        xxh3_avalanche(hash)
+    } else if len <= 128 {
+        let mut hash = (len as u64).wrapping_mul(XXH_PRIME64_1);
+        let mut k = 0;
+        while k < 4 && len > 32 * k {
+            hash = hash
+                .wrapping_add(mix16(bytes, 16 * k, 32 * k))
+                .wrapping_add(mix16(bytes, len - 16 * (k + 1), 32 * k + 16));
+            k += 1;
+        }
+        xxh3_avalanche(hash)
+    } else if len <= 240 {
+        let mut hash = (len as u64).wrapping_mul(XXH_PRIME64_1);
+        let mut k = 0;
+        while k < 8 {
+            hash = hash.wrapping_add(mix16(bytes, 16 * k, 16 * k));
+            k += 1;
+        }
+        hash = xxh3_avalanche(hash);
+        while k < len / 16 {
+            hash = hash.wrapping_add(mix16(bytes, 16 * k, 16 * (k - 8) + 3));
+            k += 1;
+        }
+        hash = hash.wrapping_add(mix16(bytes, len - 16, 136 - 17));
+        xxh3_avalanche(hash)
+    } else {
+        xxh3_64_long(bytes)
+    }
+}
+
+/// Computes the XXH3 hash of more than 240 bytes.
+const fn xxh3_64_long(bytes: &[u8]) -> u64 {
+    const STRIPE_LEN: usize = 64;
+    const STRIPES_PER_BLOCK: usize = (XXH3_SECRET.len() - STRIPE_LEN) / 8;
+    const BLOCK_LEN: usize = STRIPE_LEN * STRIPES_PER_BLOCK;
+
+    const fn accumulate(acc: [u64; 8], bytes: &[u8], i: usize, secret: usize) -> [u64; 8] {
+        let mut acc = acc;
+        let mut k = 0;
+        while k < 8 {
+            let value = read(bytes, i + 8 * k, 8);
+            let key = value ^ read(XXH3_SECRET, secret + 8 * k, 8);
+            acc[k ^ 1] = acc[k ^ 1].wrapping_add(value);
+            acc[k] = acc[k].wrapping_add((key & 0xFFFF_FFFF).wrapping_mul(key >> 32));
+            k += 1;
+        }
+        acc
+    }
+
+    const fn scramble(acc: [u64; 8]) -> [u64; 8] {
+        let mut acc = acc;
+        let mut k = 0;
+        while k < 8 {
+            let key = read(XXH3_SECRET, XXH3_SECRET.len() - STRIPE_LEN + 8 * k, 8);
+            acc[k] = (acc[k] ^ (acc[k] >> 47) ^ key).wrapping_mul(XXH_PRIME32_1);
+            k += 1;
+        }
+        acc
+    }
+
+    let len = bytes.len();
+    let mut acc = [
+        XXH_PRIME32_3,
+        XXH_PRIME64_1,
+        XXH_PRIME64_2,
+        XXH_PRIME64_3,
+        XXH_PRIME64_4,
+        XXH_PRIME32_2,
+        XXH_PRIME64_5,
+        XXH_PRIME32_1,
+    ];
+
+    let blocks = (len - 1) / BLOCK_LEN;
+    let mut block = 0;
+    while block < blocks {
+        let mut stripe = 0;
+        while stripe < STRIPES_PER_BLOCK {
+            acc = accumulate(
+                acc,
+                bytes,
+                block * BLOCK_LEN + stripe * STRIPE_LEN,
+                stripe * 8,
+            );
+            stripe += 1;
+        }
+        acc = scramble(acc);
+        block += 1;
+    }
+
+    let stripes = (len - 1 - blocks * BLOCK_LEN) / STRIPE_LEN;
+    let mut stripe = 0;
+    while stripe < stripes {
+        acc = accumulate(
+            acc,
+            bytes,
+            blocks * BLOCK_LEN + stripe * STRIPE_LEN,
+            stripe * 8,
+        );
+        stripe += 1;
+    }
+    acc = accumulate(
+        acc,
+        bytes,
+        len - STRIPE_LEN,
+        XXH3_SECRET.len() - STRIPE_LEN - 7,
+    );
+
+    let mut hash = (len as u64).wrapping_mul(XXH_PRIME64_1);
+    let mut k = 0;
+    while k < 4 {
+        hash = hash.wrapping_add(mul128_fold64(
+            acc[2 * k] ^ read(XXH3_SECRET, 11 + 16 * k, 8),
+            acc[2 * k + 1] ^ read(XXH3_SECRET, 11 + 16 * k + 8, 8),
+        ));
+        k += 1;
+    }
+    xxh3_avalanche(hash)
+}
+
+/// Mixes 16 bytes of input with 16 bytes of the XXH3 secret.
+const fn mix16(bytes: &[u8], i: usize, secret: usize) -> u64 {
+    let lo = read(bytes, i, 8) ^ read(XXH3_SECRET, secret, 8);
+    let hi = read(bytes, i + 8, 8) ^ read(XXH3_SECRET, secret + 8, 8);
+    mul128_fold64(lo, hi)
+}
+
+/// Mixes the bits of an XXH3 hash.
+const fn xxh3_avalanche(hash: u64) -> u64 {
+    let hash = (hash ^ (hash >> 37)).wrapping_mul(0x1656_6791_9E37_79F9);
+    hash ^ (hash >> 32)
+}
+
+/// Multiplies two 64-bit integers, returning the XOR of the high and low halves
+/// of the 128-bit product.
+const fn mul128_fold64(a: u64, b: u64) -> u64 {
+    let product = a as u128 * b as u128;
+    product as u64 ^ (product >> 64) as u64
+}
+
+/// Computes the [SipHash-1-3] hash of the bytes with a key.
+///
+/// The standard library's `DefaultHasher` currently uses the same algorithm,
+/// but its algorithm is unspecified and may change, so the results should not
+/// be relied on to match it.
+///
+/// [SipHash-1-3]: https://www.aumasson.jp/siphash/siphash.pdf
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(chstr::hash::sip13(b"foobar", 0, 0), 0x7738_AE0A_5DF0_9B34);
+/// assert_eq!(chstr::hash::sip13(b"foobar", 1, 2), 0x23FC_A68B_5F82_AF21);
+/// ```
+pub const fn sip13(bytes: &[u8], k0: u64, k1: u64) -> u64 {
+    const fn round(v: [u64; 4]) -> [u64; 4] {
+        let [mut v0, mut v1, mut v2, mut v3] = v;
+        v0 = v0.wrapping_add(v1);
+        v1 = v1.rotate_left(13) ^ v0;
+        v0 = v0.rotate_left(32);
+        v2 = v2.wrapping_add(v3);
+        v3 = v3.rotate_left(16) ^ v2;
+        v0 = v0.wrapping_add(v3);
+        v3 = v3.rotate_left(21) ^ v0;
+        v2 = v2.wrapping_add(v1);
+        v1 = v1.rotate_left(17) ^ v2;
+        v2 = v2.rotate_left(32);
+        [v0, v1, v2, v3]
+    }
+
+    let len = bytes.len();
+    let mut v = [
+        k0 ^ 0x736F_6D65_7073_6575,
+        k1 ^ 0x646F_7261_6E64_6F6D,
+        k0 ^ 0x6C79_6765_6E65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+
+    let mut i = 0;
+    while len - i >= 8 {
+        let m = read(bytes, i, 8);
+        v[3] ^= m;
+        v = round(v);
+        v[0] ^= m;
+        i += 8;
+    }
+
+    let m = ((len as u64) << 56) | read(bytes, i, len - i);
+    v[3] ^= m;
+    v = round(v);
+    v[0] ^= m;
+
+    v[2] ^= 0xFF;
+    v = round(round(round(v)));
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
@@ -0,0 +1,172 @@
+use crate::buf::Buf;
+
+/// Encodes constant data as hexadecimal, producing a constant `&str`.
+///
+/// The data may be a `&str`, a `&[u8]` or a `&[u8; N]`. By default, each byte
+/// is written as two lowercase digits with nothing in between. Options may
+/// follow the data:
+///
+/// - `upper` uses uppercase digits.
+/// - `separator = s` writes the `&str` `s` between each pair of bytes.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_hex;
+/// const ID: &[u8; 4] = &[0xDE, 0xAD, 0xBE, 0xEF];
+/// const HEX: &str = chstr_hex!(ID);
+///
+/// assert_eq!(HEX, "deadbeef");
+/// assert_eq!(chstr_hex!("hi"), "6869");
+/// ```
+///
+/// Options:
+/// ```
+/// # use chstr::chstr_hex;
+/// const MAC: &[u8] = &[0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+///
+/// assert_eq!(chstr_hex!(MAC, upper, separator = ":"), "00:1A:2B:3C:4D:5E");
+/// ```
+#[macro_export]
+macro_rules! chstr_hex {
+    ($data:expr $(, $opt:ident $(= $value:expr)?)* $(,)?) => {{
+        const BYTES: &[u8] = $crate::__private::Bytes($data).get();
+        const OPTIONS: $crate::__private::HexOptions =
+            $crate::__private::HexOptions::new()$(.$opt($($value)?))*;
+
+        $crate::__chstr_build!(|buf| $crate::__private::hex_encode(buf, BYTES, OPTIONS))
+    }};
+}
+
+/// Decodes a constant hexadecimal `&str`, producing a constant `&[u8; N]`.
+///
+/// Digits may be uppercase or lowercase. Accepts the same options as
+/// [`chstr_hex!`], where `separator = s` requires `s` between each pair of
+/// bytes. Invalid digits, an odd number of digits and missing separators are
+/// compile-time errors.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_hex_decode;
+/// const ID: &[u8; 4] = chstr_hex_decode!("DeadBeef");
+///
+/// assert_eq!(ID, &[0xDE, 0xAD, 0xBE, 0xEF]);
+/// assert_eq!(chstr_hex_decode!("00:1a:2b", separator = ":"), &[0x00, 0x1A, 0x2B]);
+/// ```
+///
+/// Invalid input fails to compile:
+/// ```compile_fail
+/// # use chstr::chstr_hex_decode;
+/// const BAD: &[u8] = chstr_hex_decode!("abc");
+/// ```
+#[macro_export]
+macro_rules! chstr_hex_decode {
+    ($s:expr $(, $opt:ident $(= $value:expr)?)* $(,)?) => {{
+        const STR: &str = $s;
+        const OPTIONS: $crate::__private::HexOptions =
+            $crate::__private::HexOptions::new()$(.$opt($($value)?))*;
+
+        $crate::__chstr_build_bytes!(|buf| $crate::__private::hex_decode(buf, STR, OPTIONS))
+    }};
+}
+
+/// Options for encoding and decoding hexadecimal.
+#[derive(Clone, Copy)]
+pub struct HexOptions {
+    upper: bool,
+    separator: &'static str,
+}
+
+impl HexOptions {
+    /// Returns the default options.
+    pub const fn new() -> HexOptions {
+        HexOptions {
+            upper: false,
+            separator: "",
+        }
+    }
+
+    /// Uses uppercase digits.
+    pub const fn upper(mut self) -> HexOptions {
+        self.upper = true;
+        self
+    }
+
+    /// Separates bytes with `separator`.
+    pub const fn separator(mut self, separator: &'static str) -> HexOptions {
+        self.separator = separator;
+        self
+    }
+}
+
+impl Default for HexOptions {
+    fn default() -> HexOptions {
+        HexOptions::new()
+    }
+}
+
+/// Writes the hexadecimal encoding of the bytes to the buffer.
+pub const fn hex_encode<const N: usize>(buf: Buf<N>, bytes: &[u8], options: HexOptions) -> Buf<N> {
+    let digits = if options.upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        if i > 0 {
+            buf = buf.push_str(options.separator);
+        }
+        buf = buf
+            .push_byte(digits[(bytes[i] >> 4) as usize])
+            .push_byte(digits[(bytes[i] & 0xF) as usize]);
+        i += 1;
+    }
+    buf
+}
+
+/// Writes the bytes decoded from a hexadecimal string to the buffer.
+///
+/// # Panics
+///
+/// Panics if the string is not valid hexadecimal.
+pub const fn hex_decode<const N: usize>(buf: Buf<N>, s: &str, options: HexOptions) -> Buf<N> {
+    let bytes = s.as_bytes();
+    let separator = options.separator.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !buf.is_empty() {
+            let mut k = 0;
+            while k < separator.len() {
+                assert!(
+                    i + k < bytes.len() && bytes[i + k] == separator[k],
+                    "expected a separator between hex bytes"
+                );
+                k += 1;
+            }
+            i += separator.len();
+        }
+
+        assert!(i + 1 < bytes.len(), "invalid hex length");
+        buf = buf.push_byte((value(bytes[i]) << 4) | value(bytes[i + 1]));
+        i += 2;
+    }
+    buf
+}
+
+/// Returns the value of a hexadecimal digit.
+const fn value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("invalid hex digit"),
+    }
+}
@@ -0,0 +1,130 @@
+use crate::buf::Buf;
+use crate::int::{self, Spec};
+use crate::utf8;
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str`, escaped
+/// for use as HTML or XML text.
+///
+/// Only `&`, `<` and `>` are replaced, with `&amp;`, `&lt;` and `&gt;`
+/// respectively. See [`chstr_html_attr!`] for escaping attribute values.
+///
+/// [`chstr!`]: crate::chstr
+/// [`chstr_html_attr!`]: crate::chstr_html_attr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_html;
+/// const TITLE: &str = "Fish & <Chips>";
+/// const ESCAPED: &str = chstr_html!["<h1>", TITLE, "</h1>"];
+///
+/// assert_eq!(ESCAPED, "&lt;h1&gt;Fish &amp; &lt;Chips&gt;&lt;/h1&gt;");
+/// ```
+#[macro_export]
+macro_rules! chstr_html {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| {
+            $crate::__private::escape_html(buf, STR, $crate::__private::HtmlEscape::Minimal)
+        })
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str`, escaped
+/// for use in a quoted HTML or XML attribute value.
+///
+/// Characters are escaped as by [`chstr_html!`], and double and single quotes
+/// are also replaced with `&quot;` and `&#39;` respectively.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_html_attr;
+/// const LABEL: &str = "Say \"it's <ok>\"";
+/// const ESCAPED: &str = chstr_html_attr![LABEL];
+///
+/// assert_eq!(ESCAPED, "Say &quot;it&#39;s &lt;ok&gt;&quot;");
+/// ```
+#[macro_export]
+macro_rules! chstr_html_attr {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| {
+            $crate::__private::escape_html(buf, STR, $crate::__private::HtmlEscape::Attribute)
+        })
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str`, escaped
+/// for use in HTML or XML and containing only ASCII characters.
+///
+/// Characters are escaped as by [`chstr_html_attr!`], and non-ASCII characters
+/// are also replaced with decimal numeric character references, such as
+/// `&#233;`.
+///
+/// [`chstr!`]: crate::chstr
+/// [`chstr_html_attr!`]: crate::chstr_html_attr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_html_ascii;
+/// const NAME: &str = "Café \"🦀\"";
+/// const ESCAPED: &str = chstr_html_ascii![NAME];
+///
+/// assert_eq!(ESCAPED, "Caf&#233; &quot;&#129408;&quot;");
+/// ```
+#[macro_export]
+macro_rules! chstr_html_ascii {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| {
+            $crate::__private::escape_html(buf, STR, $crate::__private::HtmlEscape::Ascii)
+        })
+    }};
+}
+
+/// The set of characters replaced by [`escape_html`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HtmlEscape {
+    /// Escapes `&`, `<` and `>`.
+    Minimal,
+    /// Escapes `&`, `<`, `>`, `"` and `'`.
+    Attribute,
+    /// Escapes `&`, `<`, `>`, `"`, `'` and all non-ASCII characters.
+    Ascii,
+}
+
+/// Writes a string to the buffer, escaped for use in HTML or XML.
+pub const fn escape_html<const N: usize>(buf: Buf<N>, s: &str, set: HtmlEscape) -> Buf<N> {
+    let quotes = !matches!(set, HtmlEscape::Minimal);
+    let ascii = matches!(set, HtmlEscape::Ascii);
+
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' if quotes => buf.push_str("&quot;"),
+            '\'' if quotes => buf.push_str("&#39;"),
+            _ if ascii && !c.is_ascii() => {
+                let buf = buf.push_str("&#");
+                let buf = int::write(buf, false, c as u128, Spec::DECIMAL);
+                buf.push_byte(b';')
+            }
+            _ => buf.push_slice(s, i, i + len),
+        };
+        i += len;
+    }
+    buf
+}
@@ -0,0 +1,268 @@
+use crate::buf::Buf;
+use crate::case;
+use crate::tables;
+use crate::utf8;
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// `snake_case`.
+///
+/// The input is split into words at whitespace and ASCII punctuation, which
+/// are removed, and at changes in case: before an uppercase letter that does
+/// not follow another uppercase letter, and before the last uppercase letter in
+/// a run of uppercase letters that is followed by a lowercase letter. The words are
+/// then written in lowercase, separated by `_`.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_snake;
+/// const NAME: &str = "XMLHttpRequest";
+/// const SNAKE: &str = chstr_snake![NAME];
+///
+/// assert_eq!(SNAKE, "xml_http_request");
+/// assert_eq!(chstr_snake!["user-ID v2Beta"], "user_id_v2_beta");
+/// ```
+#[macro_export]
+macro_rules! chstr_snake {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_snake_case(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// `kebab-case`.
+///
+/// Words are split as by [`chstr_snake!`], written in lowercase and separated
+/// by `-`.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_kebab;
+/// const NAME: &str = "XMLHttpRequest";
+/// const KEBAB: &str = chstr_kebab![NAME];
+///
+/// assert_eq!(KEBAB, "xml-http-request");
+/// ```
+#[macro_export]
+macro_rules! chstr_kebab {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_kebab_case(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// `SCREAMING_SNAKE_CASE`.
+///
+/// Words are split as by [`chstr_snake!`], written in uppercase and separated
+/// by `_`.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_screaming_snake;
+/// const APP: &str = "myApp";
+/// const VAR: &str = chstr_screaming_snake![APP, " log level"];
+///
+/// assert_eq!(VAR, "MY_APP_LOG_LEVEL");
+/// ```
+#[macro_export]
+macro_rules! chstr_screaming_snake {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_screaming_snake_case(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// `camelCase`.
+///
+/// Words are split as by [`chstr_snake!`]. The first word is written in
+/// lowercase, and each following word is capitalized: its first character is
+/// written in titlecase and the rest in lowercase.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_camel;
+/// const NAME: &str = "XMLHttpRequest";
+/// const CAMEL: &str = chstr_camel![NAME];
+///
+/// assert_eq!(CAMEL, "xmlHttpRequest");
+/// assert_eq!(chstr_camel!["user_id"], "userId");
+/// assert_eq!(chstr_camel!["ǆemal ßtraße"], "ǆemalSstraße");
+/// ```
+#[macro_export]
+macro_rules! chstr_camel {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_camel_case(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// `PascalCase`.
+///
+/// Words are split as by [`chstr_snake!`], and each word is capitalized: its
+/// first character is written in titlecase and the rest in lowercase.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_pascal;
+/// const NAME: &str = "xml_http_request";
+/// const PASCAL: &str = chstr_pascal![NAME];
+///
+/// assert_eq!(PASCAL, "XmlHttpRequest");
+/// assert_eq!(chstr_pascal!["émile zola"], "ÉmileZola");
+/// ```
+///
+/// Titlecase mappings:
+/// ```
+/// # use chstr::chstr_pascal;
+/// const NAME: &str = chstr_pascal!["ǆemal ßtraße"];
+///
+/// assert_eq!(NAME, "ǅemalSstraße");
+/// assert_eq!(chstr_pascal!["ǄEMAL ΟΔΟΣ"], "ǅemalΟδος");
+/// ```
+#[macro_export]
+macro_rules! chstr_pascal {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::to_pascal_case(buf, STR))
+    }};
+}
+
+/// An identifier case style.
+#[derive(Clone, Copy)]
+enum Style {
+    Snake,
+    Kebab,
+    ScreamingSnake,
+    Camel,
+    Pascal,
+}
+
+/// Writes a string to the buffer in `snake_case`.
+pub const fn to_snake_case<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    convert(buf, s, Style::Snake)
+}
+
+/// Writes a string to the buffer in `kebab-case`.
+pub const fn to_kebab_case<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    convert(buf, s, Style::Kebab)
+}
+
+/// Writes a string to the buffer in `SCREAMING_SNAKE_CASE`.
+pub const fn to_screaming_snake_case<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    convert(buf, s, Style::ScreamingSnake)
+}
+
+/// Writes a string to the buffer in `camelCase`.
+pub const fn to_camel_case<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    convert(buf, s, Style::Camel)
+}
+
+/// Writes a string to the buffer in `PascalCase`.
+pub const fn to_pascal_case<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    convert(buf, s, Style::Pascal)
+}
+
+/// Writes the words of a string to the buffer in a case style.
+const fn convert<const N: usize>(buf: Buf<N>, s: &str, style: Style) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut words = 0;
+    let mut i = 0;
+    loop {
+        let (start, end) = next_word(bytes, i);
+        if start == end {
+            break;
+        }
+
+        if words > 0 {
+            match style {
+                Style::Snake | Style::ScreamingSnake => buf = buf.push_byte(b'_'),
+                Style::Kebab => buf = buf.push_byte(b'-'),
+                Style::Camel | Style::Pascal => {}
+            }
+        }
+
+        buf = match style {
+            Style::Snake | Style::Kebab => case::write_lowercase(buf, bytes, start, end),
+            Style::ScreamingSnake => case::write_uppercase(buf, bytes, start, end),
+            Style::Camel if words == 0 => case::write_lowercase(buf, bytes, start, end),
+            Style::Camel | Style::Pascal => case::write_titlecase(buf, bytes, start, end),
+        };
+
+        words += 1;
+        i = end;
+    }
+    buf
+}
+
+/// Returns the byte range of the next word at or after byte `i` of a string.
+///
+/// If there are no more words, the range is empty.
+const fn next_word(bytes: &[u8], i: usize) -> (usize, usize) {
+    let mut i = i;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        if !is_separator(c) {
+            break;
+        }
+        i += len;
+    }
+
+    let start = i;
+    let mut prev = '\0';
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        if is_separator(c) {
+            break;
+        }
+
+        if i > start && case::is_uppercase(c) {
+            if !case::is_uppercase(prev) {
+                break;
+            }
+
+            // The last uppercase letter of an acronym starts the next word.
+            if i + len < bytes.len() {
+                let (next, _) = utf8::decode(bytes, i + len);
+                if case::is_lowercase(next) {
+                    break;
+                }
+            }
+        }
+
+        prev = c;
+        i += len;
+    }
+
+    (start, i)
+}
+
+/// Returns `true` if a character separates words.
+const fn is_separator(c: char) -> bool {
+    (c.is_ascii() && !c.is_ascii_alphanumeric()) || tables::is_whitespace(c)
+}
@@ -0,0 +1,239 @@
+use crate::buf::Buf;
+
+/// The maximum number of digits in a `u128`, written in binary.
+const MAX_DIGITS: usize = 128;
+
+/// Formatting options for an integer argument to [`chstr!`](crate::chstr).
+///
+/// By default an integer is written in decimal, with no minimum width. Negative
+/// values written in a radix other than 10 are written as their two's
+/// complement, in the same way as the [`LowerHex`](core::fmt::LowerHex),
+/// [`Octal`](core::fmt::Octal) and [`Binary`](core::fmt::Binary) formatting
+/// traits.
+///
+/// # Examples
+///
+/// Hexadecimal register names:
+/// ```
+/// # use chstr::{chstr, Int};
+/// const REG: u16 = 0x1f;
+/// const NAME: &str = chstr!["reg_", Int::new(REG).upper_hex().width(4).fill('0')];
+///
+/// assert_eq!(NAME, "reg_001F");
+/// ```
+///
+/// Binary masks and octal permissions:
+/// ```
+/// # use chstr::{chstr, Int};
+/// const MASK: u8 = 0b1010;
+/// const MODE: u32 = 0o755;
+///
+/// assert_eq!(chstr![Int::new(MASK).binary().width(8).fill('0')], "00001010");
+/// assert_eq!(chstr![Int::new(MASK).binary().width(10).fill('0').prefix()], "0b00001010");
+/// assert_eq!(chstr![Int::new(MODE).octal()], "755");
+/// assert_eq!(chstr![Int::new(-1i8).hex().prefix()], "0xff");
+/// ```
+///
+/// Arbitrary radix and fill characters:
+/// ```
+/// # use chstr::{chstr, Int};
+/// assert_eq!(chstr![Int::new(35u8).radix(36)], "z");
+/// assert_eq!(chstr![Int::new(35u8).radix(36).uppercase()], "Z");
+/// assert_eq!(chstr![Int::new(-42i32).width(6).fill('0')], "-00042");
+/// assert_eq!(chstr![Int::new(-42i32).width(6).fill('·')], "···-42");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Int<T> {
+    pub(crate) value: T,
+    pub(crate) spec: Spec,
+}
+
+/// The formatting options of an [`Int`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Spec {
+    radix: u32,
+    uppercase: bool,
+    prefix: bool,
+    width: usize,
+    fill: char,
+}
+
+impl Spec {
+    /// The default formatting options, writing an integer in decimal.
+    pub(crate) const DECIMAL: Spec = Spec {
+        radix: 10,
+        uppercase: false,
+        prefix: false,
+        width: 0,
+        fill: ' ',
+    };
+
+    /// Options for writing an integer in lowercase hexadecimal.
+    pub(crate) const LOWER_HEX: Spec = Spec {
+        radix: 16,
+        ..Spec::DECIMAL
+    };
+
+    /// Returns `true` if negative values are written with a sign.
+    pub(crate) const fn is_signed(&self) -> bool {
+        self.radix == 10
+    }
+}
+
+impl<T> Int<T> {
+    /// Formats an integer in decimal.
+    pub const fn new(value: T) -> Int<T> {
+        Int {
+            value,
+            spec: Spec::DECIMAL,
+        }
+    }
+
+    /// Formats the integer in the given radix.
+    ///
+    /// Digits above 9 are written as lowercase letters, unless
+    /// [`uppercase`](Int::uppercase) is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub const fn radix(mut self, radix: u32) -> Int<T> {
+        assert!(
+            radix >= 2 && radix <= 36,
+            "radix must be in the range `2..=36`"
+        );
+        self.spec.radix = radix;
+        self
+    }
+
+    /// Formats the integer in lowercase hexadecimal.
+    pub const fn hex(self) -> Int<T> {
+        self.radix(16)
+    }
+
+    /// Formats the integer in uppercase hexadecimal.
+    pub const fn upper_hex(self) -> Int<T> {
+        self.radix(16).uppercase()
+    }
+
+    /// Formats the integer in octal.
+    pub const fn octal(self) -> Int<T> {
+        self.radix(8)
+    }
+
+    /// Formats the integer in binary.
+    pub const fn binary(self) -> Int<T> {
+        self.radix(2)
+    }
+
+    /// Writes digits above 9 as uppercase letters.
+    pub const fn uppercase(mut self) -> Int<T> {
+        self.spec.uppercase = true;
+        self
+    }
+
+    /// Writes a `0x`, `0o` or `0b` prefix before the digits.
+    ///
+    /// The prefix may be set before or after the radix, but the integer fails
+    /// to be written if the radix is not 16, 8 or 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use chstr::{chstr, Int};
+    /// assert_eq!(chstr![Int::new(255u8).prefix().hex()], "0xff");
+    /// ```
+    ///
+    /// A prefix in another radix fails to compile:
+    /// ```compile_fail
+    /// # use chstr::{chstr, Int};
+    /// const BAD: &str = chstr![Int::new(5u8).hex().prefix().radix(10)];
+    /// ```
+    pub const fn prefix(mut self) -> Int<T> {
+        self.spec.prefix = true;
+        self
+    }
+
+    /// Pads the integer on the left to a minimum width in characters.
+    ///
+    /// The width includes any sign and prefix.
+    pub const fn width(mut self, width: usize) -> Int<T> {
+        self.spec.width = width;
+        self
+    }
+
+    /// Sets the character used for padding, which is a space by default.
+    ///
+    /// When the fill character is `'0'`, padding is inserted after any sign and
+    /// prefix, in the same way as the `0` flag of [`format!`].
+    ///
+    /// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+    pub const fn fill(mut self, fill: char) -> Int<T> {
+        self.spec.fill = fill;
+        self
+    }
+}
+
+/// Writes an integer to the buffer.
+///
+/// # Panics
+///
+/// Panics if a prefix is set and the radix is not 16, 8 or 2.
+pub(crate) const fn write<const N: usize>(buf: Buf<N>, neg: bool, abs: u128, spec: Spec) -> Buf<N> {
+    let mut digits = [0; MAX_DIGITS];
+    let mut len = 0;
+
+    let radix = spec.radix as u128;
+    let mut n = abs;
+    loop {
+        let d = (n % radix) as u8;
+        digits[len] = match d {
+            0..=9 => b'0' + d,
+            _ if spec.uppercase => b'A' + d - 10,
+            _ => b'a' + d - 10,
+        };
+        len += 1;
+        n /= radix;
+
+        if n == 0 {
+            break;
+        }
+    }
+
+    let prefix: &[u8] = match (spec.prefix, spec.radix) {
+        (false, _) => b"",
+        (true, 2) => b"0b",
+        (true, 8) => b"0o",
+        (true, 16) => b"0x",
+        _ => panic!("a prefix is only supported in radix 2, 8 or 16"),
+    };
+
+    let width = neg as usize + prefix.len() + len;
+    let mut padding = spec.width.saturating_sub(width);
+
+    let mut buf = buf;
+    if spec.fill != '0' {
+        while padding > 0 {
+            buf = buf.push_char(spec.fill);
+            padding -= 1;
+        }
+    }
+    if neg {
+        buf = buf.push_byte(b'-');
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        buf = buf.push_byte(prefix[i]);
+        i += 1;
+    }
+    while padding > 0 {
+        buf = buf.push_byte(b'0');
+        padding -= 1;
+    }
+    while len > 0 {
+        len -= 1;
+        buf = buf.push_byte(digits[len]);
+    }
+
+    buf
+}
@@ -0,0 +1,118 @@
+use crate::buf::Buf;
+use crate::utf8;
+
+/// Converts a sequence of [`chstr!`] arguments into the body of a constant
+/// JSON string literal.
+///
+/// Quotes and backslashes are escaped with a backslash, the `\b`, `\f`, `\n`,
+/// `\r` and `\t` control characters use their short escapes, and all other
+/// control characters are escaped as `\u00XX`. The result does not include the
+/// surrounding quotes. See [`chstr_json_ascii!`] to also escape non-ASCII
+/// characters.
+///
+/// [`chstr!`]: crate::chstr
+/// [`chstr_json_ascii!`]: crate::chstr_json_ascii
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_json;
+/// const VALUE: &str = "say \"hé\"\n";
+/// const ESCAPED: &str = chstr_json![VALUE];
+///
+/// assert_eq!(ESCAPED, r#"say \"hé\"\n"#);
+/// assert_eq!(chstr_json!['\u{1F}', '\\'], r"\u001f\\");
+/// ```
+///
+/// Composing a JSON fragment:
+/// ```
+/// # use chstr::{chformat, chstr_json};
+/// const KEY: &str = "name";
+/// const VALUE: &str = "\"quoted\"";
+/// const FRAGMENT: &str = chformat!(r#"{{"{}":"{}"}}"#, chstr_json![KEY], chstr_json![VALUE]);
+///
+/// assert_eq!(FRAGMENT, r#"{"name":"\"quoted\""}"#);
+/// ```
+#[macro_export]
+macro_rules! chstr_json {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::escape_json(buf, STR, false))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into the body of a constant
+/// JSON string literal, containing only ASCII characters.
+///
+/// Characters are escaped as by [`chstr_json!`], and non-ASCII characters are
+/// also escaped as `\uXXXX`, using a surrogate pair for characters outside of
+/// the Basic Multilingual Plane.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_json_ascii;
+/// const VALUE: &str = "hé 🦀";
+/// const ESCAPED: &str = chstr_json_ascii![VALUE];
+///
+/// assert_eq!(ESCAPED, r"h\u00e9 \ud83e\udd80");
+/// ```
+#[macro_export]
+macro_rules! chstr_json_ascii {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::escape_json(buf, STR, true))
+    }};
+}
+
+/// Writes a string to the buffer, escaped for use in a JSON string literal.
+///
+/// If `ascii` is `true`, non-ASCII characters are also escaped.
+pub const fn escape_json<const N: usize>(buf: Buf<N>, s: &str, ascii: bool) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        buf = match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\u{8}' => buf.push_str("\\b"),
+            '\u{C}' => buf.push_str("\\f"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\0'..='\u{1F}' => push_escape(buf, c as u16),
+            _ if ascii && !c.is_ascii() => {
+                let code = c as u32;
+                if code < 0x10000 {
+                    push_escape(buf, code as u16)
+                } else {
+                    let code = code - 0x10000;
+                    let buf = push_escape(buf, (0xD800 | (code >> 10)) as u16);
+                    push_escape(buf, (0xDC00 | (code & 0x3FF)) as u16)
+                }
+            }
+            _ => buf.push_slice(s, i, i + len),
+        };
+        i += len;
+    }
+    buf
+}
+
+/// Writes a UTF-16 code unit to the buffer as a `\uXXXX` escape.
+const fn push_escape<const N: usize>(buf: Buf<N>, unit: u16) -> Buf<N> {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    buf.push_str("\\u")
+        .push_byte(HEX_DIGITS[(unit >> 12) as usize & 0xF])
+        .push_byte(HEX_DIGITS[(unit >> 8) as usize & 0xF])
+        .push_byte(HEX_DIGITS[(unit >> 4) as usize & 0xF])
+        .push_byte(HEX_DIGITS[unit as usize & 0xF])
+}
@@ -1,6 +1,97 @@
 #![no_std]
 
-/// Converts a `char` array into a constant `&str`.
+mod arg;
+mod base32;
+mod base58;
+mod base64;
+mod buf;
+mod bytes;
+mod case;
+mod escape;
+mod format;
+#[cfg(feature = "unicode-segmentation")]
+mod grapheme;
+pub mod hash;
+mod hex;
+mod html;
+mod ident;
+mod int;
+mod json;
+mod map;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+mod pattern;
+mod percent;
+mod replace;
+mod rev;
+mod slice;
+mod split;
+mod tables;
+mod trim;
+mod utf8;
+#[cfg(feature = "unicode-width")]
+mod width;
+
+pub use crate::int::Int;
+pub use crate::map::Map;
+pub use crate::pattern::{AsciiWhitespace, Whitespace};
+pub use crate::percent::PercentEncodeSet;
+pub use crate::slice::{char_at, char_count};
+#[cfg(feature = "unicode-width")]
+pub use crate::width::display_width;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::arg::Arg;
+    pub use crate::base32::{base32_decode, base32_encode, Base32Options};
+    pub use crate::base58::{
+        base58_bytes, base58_bytes_len, base58_decode, base58_digits, base58_digits_len,
+        base58_encode,
+    };
+    pub use crate::base64::{base64_decode, base64_encode, Base64Options};
+    pub use crate::buf::Buf;
+    pub use crate::bytes::Bytes;
+    pub use crate::case::{
+        to_ascii_lowercase, to_ascii_uppercase, to_lowercase, to_simple_lowercase,
+        to_simple_uppercase, to_uppercase,
+    };
+    pub use crate::escape::{escape_debug, escape_default, escape_unicode};
+    pub use crate::format::format;
+    pub use crate::hex::{hex_decode, hex_encode, HexOptions};
+    pub use crate::html::{escape_html, HtmlEscape};
+    pub use crate::ident::{
+        to_camel_case, to_kebab_case, to_pascal_case, to_screaming_snake_case, to_snake_case,
+    };
+    pub use crate::json::escape_json;
+    pub use crate::map::MapTable;
+    #[cfg(feature = "unicode-normalization")]
+    pub use crate::normalize::{normalize, NormalizationForm};
+    pub use crate::pattern::{CharSet, Needle, Pattern};
+    pub use crate::percent::{percent_decode, percent_encode};
+    pub use crate::replace::replace;
+    pub use crate::rev::reverse;
+    #[cfg(feature = "unicode-segmentation")]
+    pub use crate::rev::{grapheme_starts, reverse_graphemes};
+    pub use crate::slice::{slice_bytes, slice_chars, Bounds};
+    pub use crate::split::{split, SplitOptions};
+    pub use crate::trim::{strip_prefix, strip_suffix, trim};
+    #[cfg(feature = "unicode-width")]
+    pub use crate::width::{pad, truncate};
+}
+
+/// Converts a sequence of `char`, `&str`, `bool` and integer constants into a
+/// constant `&str`.
+///
+/// Integers are written in decimal, unless formatted with [`Int`]. Integer
+/// literals must have a type suffix, as the type of the argument decides how it
+/// is written.
+///
+/// Character ranges, such as `'a'..='z'`, are expanded to each character in
+/// the range, skipping surrogates. A range may expand to at most 4096
+/// characters.
+///
+/// A single argument can be repeated with `chstr![arg; n]`, where `n` is a
+/// constant `usize`.
 ///
 /// # Examples
 ///
@@ -22,8 +113,209 @@
 /// assert_eq!(chars.next(), Some(SEPARATOR_CHAR));
 /// assert_eq!(chars.next(), None);
 /// ```
+///
+/// Mixing characters and strings:
+/// ```
+/// # use chstr::chstr;
+/// const ROOT: &str = "etc";
+/// const SEPARATOR_CHAR: char = '/';
+/// const CONFIG: &str = chstr![ROOT, SEPARATOR_CHAR, "config"];
+///
+/// assert_eq!(CONFIG, "etc/config");
+/// ```
+///
+/// Integer constants:
+/// ```
+/// # use chstr::chstr;
+/// const MAJOR: u32 = 1;
+/// const MINOR: u32 = 42;
+/// const VERSION: &str = chstr!['v', MAJOR, '.', MINOR];
+///
+/// assert_eq!(VERSION, "v1.42");
+///
+/// const LIMITS: &str = chstr![i8::MIN, ' ', u128::MAX, ' ', 0u8];
+/// assert_eq!(LIMITS, "-128 340282366920938463463374607431768211455 0");
+/// ```
+///
+/// Character ranges:
+/// ```
+/// # use chstr::chstr;
+/// const ALPHANUMERIC: &str = chstr!['a'..='z', 'A'..='Z', '0'..='9'];
+///
+/// assert_eq!(ALPHANUMERIC.len(), 62);
+/// assert!(ALPHANUMERIC.starts_with("abc"));
+/// assert!(ALPHANUMERIC.ends_with("789"));
+///
+/// const AROUND_SURROGATES: &str = chstr!['\u{D7FE}'..'\u{E001}'];
+/// assert_eq!(AROUND_SURROGATES, "\u{D7FE}\u{D7FF}\u{E000}");
+/// ```
+///
+/// Ranges that are too long fail to compile:
+/// ```compile_fail
+/// # use chstr::chstr;
+/// const TOO_LONG: &str = chstr!['\0'..=char::MAX];
+/// ```
+///
+/// Repetition:
+/// ```
+/// # use chstr::chstr;
+/// const RULE_CHAR: char = '─';
+/// const WIDTH: usize = 80;
+/// const RULE: &str = chstr![RULE_CHAR; WIDTH];
+///
+/// assert_eq!(RULE.chars().count(), 80);
+/// assert_eq!(RULE.len(), 80 * RULE_CHAR.len_utf8());
+/// assert_eq!(chstr!["ab"; 3], "ababab");
+/// assert_eq!(chstr![' '; 0], "");
+/// ```
 #[macro_export]
 macro_rules! chstr {
+    [$arg:expr; $n:expr] => {
+        $crate::__chstr_build!(|buf| {
+            const COUNT: usize = $n;
+
+            let mut buf = buf;
+            let mut i = 0;
+            while i < COUNT {
+                buf = $crate::__private::Arg($arg).write(buf);
+                i += 1;
+            }
+            buf
+        })
+    };
+    [$($arg:expr),* $(,)?] => {
+        $crate::__chstr_build!(|buf| {
+            $(let buf = $crate::__private::Arg($arg).write(buf);)*
+            buf
+        })
+    };
+}
+
+/// Formats `char`, `&str`, `bool` and integer constants into a constant `&str`
+/// using a template.
+///
+/// Each `{}` placeholder in the template is replaced with the next argument,
+/// written as by [`chstr!`]. Literal braces are escaped by doubling them, as
+/// `{{` and `}}`. A mismatch between the number of placeholders and arguments
+/// is a compile-time error.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chformat;
+/// const NAME: &str = "chstr";
+/// const MAJOR: u32 = 1;
+/// const MINOR: u32 = 42;
+/// const MESSAGE: &str = chformat!("{} v{}.{} (stable: {})", NAME, MAJOR, MINOR, true);
+///
+/// assert_eq!(MESSAGE, "chstr v1.42 (stable: true)");
+/// ```
+///
+/// Escaped braces and formatted integers:
+/// ```
+/// # use chstr::{chformat, Int};
+/// const CODE: u16 = 0xbeef;
+/// const OBJECT: &str = chformat!("{{ code: {} }}", Int::new(CODE).hex().prefix());
+///
+/// assert_eq!(OBJECT, "{ code: 0xbeef }");
+/// ```
+///
+/// Too few arguments fail to compile:
+/// ```compile_fail
+/// # use chstr::chformat;
+/// const BAD: &str = chformat!("{} and {}", 'a');
+/// ```
+///
+/// Too many arguments fail to compile:
+/// ```compile_fail
+/// # use chstr::chformat;
+/// const BAD: &str = chformat!("{}", 'a', 'b');
+/// ```
+#[macro_export]
+macro_rules! chformat {
+    ($fmt:expr $(, $arg:expr)* $(,)?) => {{
+        const ARGS: &[&str] = &[$($crate::chstr![$arg]),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::format(buf, $fmt, ARGS))
+    }};
+}
+
+/// Builds a constant `&str` from an expression that writes to a buffer.
+///
+/// The expression is evaluated twice, first to compute the length of the
+/// string and then to fill a buffer of that length.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chstr_build {
+    (|$buf:ident| $body:expr) => {{
+        const LEN: usize = {
+            let $buf = $crate::__private::Buf::<0>::new();
+            $body
+        }
+        .len();
+
+        const BUF: [u8; LEN] = {
+            let $buf = $crate::__private::Buf::<LEN>::new();
+            $body
+        }
+        .into_array();
+
+        // SAFETY: The buffer is only written to with whole characters and
+        //         string slices, so it contains valid UTF-8.
+        unsafe { ::core::str::from_utf8_unchecked(&BUF) }
+    }};
+}
+
+/// Builds a constant `&[u8; N]` from an expression that writes to a buffer.
+///
+/// The expression is evaluated twice, as by [`__chstr_build!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chstr_build_bytes {
+    (|$buf:ident| $body:expr) => {{
+        const LEN: usize = {
+            let $buf = $crate::__private::Buf::<0>::new();
+            $body
+        }
+        .len();
+
+        const BUF: [u8; LEN] = {
+            let $buf = $crate::__private::Buf::<LEN>::new();
+            $body
+        }
+        .into_array();
+
+        &BUF
+    }};
+}
+
+/// Converts a `char` array into a constant UTF-16 encoded `&[u16]`.
+///
+/// Characters outside the Basic Multilingual Plane are encoded as surrogate
+/// pairs.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr16;
+/// const ABC: &[u16] = chstr16!['a', 'b', 'c'];
+///
+/// assert_eq!(ABC, &[0x61, 0x62, 0x63]);
+/// ```
+///
+/// Supplementary characters:
+/// ```
+/// # use chstr::chstr16;
+/// const CRAB: char = '🦀';
+/// const WIDE: &[u16] = chstr16!['<', CRAB, '>'];
+///
+/// assert_eq!(WIDE, "<🦀>".encode_utf16().collect::<Vec<u16>>());
+/// assert_eq!(String::from_utf16(WIDE).unwrap(), "<🦀>");
+/// ```
+#[macro_export]
+macro_rules! chstr16 {
     [$($c:expr),* $(,)?] => {
         {
             const CHARS: &[char] = &[$($c),*];
@@ -35,19 +327,17 @@ macro_rules! chstr {
                 let mut i = 0;
                 while i < N {
                     let c = CHARS[i];
-                    len += c.len_utf8();
+                    len += c.len_utf16();
                     i += 1;
                 }
 
                 len
             };
 
-            const BUF: [u8; LEN] = {
-                // UTF-8 ranges and tags for encoding characters.
-                const TAG_CONT: u8 = 0b1000_0000;
-                const TAG_TWO_B: u8 = 0b1100_0000;
-                const TAG_THREE_B: u8 = 0b1110_0000;
-                const TAG_FOUR_B: u8 = 0b1111_0000;
+            const BUF: [u16; LEN] = {
+                // UTF-16 surrogate ranges for encoding supplementary characters.
+                const HIGH_SURROGATE: u32 = 0xD800;
+                const LOW_SURROGATE: u32 = 0xDC00;
 
                 let mut buf = [0; LEN];
                 let mut offset = 0;
@@ -56,26 +346,16 @@ macro_rules! chstr {
                 while i < N {
                     let c = CHARS[i];
                     let code = c as u32;
-                    let len = c.len_utf8();
+                    let len = c.len_utf16();
 
                     match len {
                         1 => {
-                            buf[offset + 0] = code as u8;
+                            buf[offset] = code as u16;
                         }
                         2 => {
-                            buf[offset + 0] = (code >> 6 & 0x1F) as u8 | TAG_TWO_B;
-                            buf[offset + 1] = (code & 0x3F) as u8 | TAG_CONT;
-                        }
-                        3 => {
-                            buf[offset + 0] = (code >> 12 & 0x0F) as u8 | TAG_THREE_B;
-                            buf[offset + 1] = (code >> 6 & 0x3F) as u8 | TAG_CONT;
-                            buf[offset + 2] = (code & 0x3F) as u8 | TAG_CONT;
-                        }
-                        4 => {
-                            buf[offset + 0] = (code >> 18 & 0x07) as u8 | TAG_FOUR_B;
-                            buf[offset + 1] = (code >> 12 & 0x3F) as u8 | TAG_CONT;
-                            buf[offset + 2] = (code >> 6 & 0x3F) as u8 | TAG_CONT;
-                            buf[offset + 3] = (code & 0x3F) as u8 | TAG_CONT;
+                            let code = code - 0x10000;
+                            buf[offset] = (HIGH_SURROGATE | (code >> 10)) as u16;
+                            buf[offset + 1] = (LOW_SURROGATE | (code & 0x3FF)) as u16;
                         }
                         _ => ::core::unreachable!(),
                     }
@@ -87,7 +367,108 @@ macro_rules! chstr {
                 buf
             };
 
-            unsafe { ::core::str::from_utf8_unchecked(&BUF) }
+            &BUF as &[u16]
+        }
+    };
+}
+
+/// Converts a `char` array into a constant NUL-terminated `&[u8]`.
+///
+/// The characters are encoded as UTF-8 by [`chstr!`] and followed by a single
+/// terminating NUL byte. Interior `'\0'` characters are rejected at compile
+/// time.
+///
+/// See [`chcstr!`] for a version that yields a [`CStr`](core::ffi::CStr).
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_nul;
+/// const ABC: &[u8] = chstr_nul!['a', 'b', 'c'];
+///
+/// assert_eq!(ABC, b"abc\0");
+/// ```
+///
+/// Passing to C as a pointer:
+/// ```
+/// # use chstr::chstr_nul;
+/// use std::os::raw::c_char;
+///
+/// const NAME: &[u8] = chstr_nul!['i', 'd'];
+///
+/// let ptr: *const c_char = NAME.as_ptr().cast();
+/// # assert!(!ptr.is_null());
+/// ```
+///
+/// Interior NUL characters fail to compile:
+/// ```compile_fail
+/// # use chstr::chstr_nul;
+/// const BAD: &[u8] = chstr_nul!['a', '\0', 'b'];
+/// ```
+#[macro_export]
+macro_rules! chstr_nul {
+    [$($c:expr),* $(,)?] => {
+        {
+            const STR: &str = $crate::chstr![$($c),*];
+            const LEN: usize = STR.len() + 1;
+
+            const BUF: [u8; LEN] = {
+                let bytes = STR.as_bytes();
+
+                let mut buf = [0; LEN];
+
+                let mut i = 0;
+                while i < bytes.len() {
+                    if bytes[i] == 0 {
+                        ::core::panic!("interior nul character in C string");
+                    }
+                    buf[i] = bytes[i];
+                    i += 1;
+                }
+
+                buf
+            };
+
+            &BUF as &[u8]
+        }
+    };
+}
+
+/// Converts a `char` array into a constant [`&CStr`](core::ffi::CStr).
+///
+/// This is a wrapper around [`chstr_nul!`], which can be used directly on
+/// compilers that cannot construct a `CStr` in a constant context.
+///
+/// *Compiler support: requires rustc 1.72+*
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chcstr;
+/// use std::ffi::CStr;
+///
+/// const ABC: &CStr = chcstr!['a', 'b', 'c'];
+///
+/// assert_eq!(ABC.to_bytes_with_nul(), b"abc\0");
+/// assert_eq!(ABC.to_str(), Ok("abc"));
+/// ```
+///
+/// Interior NUL characters fail to compile:
+/// ```compile_fail
+/// # use chstr::chcstr;
+/// # use std::ffi::CStr;
+/// const BAD: &CStr = chcstr!['a', '\0', 'b'];
+/// ```
+#[macro_export]
+macro_rules! chcstr {
+    [$($c:expr),* $(,)?] => {
+        {
+            const BYTES: &[u8] = $crate::chstr_nul![$($c),*];
+
+            // SAFETY: `chstr_nul!` guarantees a single, terminating NUL byte.
+            unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(BYTES) }
         }
     };
 }
@@ -0,0 +1,369 @@
+use crate::hash;
+
+/// Builds a constant [`Map`](crate::Map) from `&str` keys and values.
+///
+/// Keys are constant `&str`s, which may be built with [`chstr!`]. A minimal
+/// perfect hash function for the keys is found at compile time, so each lookup
+/// hashes the key once and compares it with at most one entry.
+///
+/// Building the map takes time linear in the number of keys. Maps of more than
+/// about 20,000 keys take long enough to trigger the `long_running_const_eval`
+/// lint, which can be allowed to build larger maps. Duplicate keys are a
+/// compile-time error.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::{chstr, chstr_map, Map};
+/// #[derive(Debug, PartialEq)]
+/// enum Keyword {
+///     If,
+///     Else,
+///     While,
+/// }
+///
+/// const PREFIX: &str = "#";
+/// static KEYWORDS: Map<Keyword> = chstr_map! {
+///     "if" => Keyword::If,
+///     "else" => Keyword::Else,
+///     chstr![PREFIX, "while"] => Keyword::While,
+/// };
+///
+/// assert_eq!(KEYWORDS.get("if"), Some(&Keyword::If));
+/// assert_eq!(KEYWORDS.get("#while"), Some(&Keyword::While));
+/// assert_eq!(KEYWORDS.get("for"), None);
+/// ```
+///
+/// Duplicate keys fail to compile:
+/// ```compile_fail
+/// # use chstr::{chstr_map, Map};
+/// static BAD: Map<u32> = chstr_map! {
+///     "a" => 1,
+///     "a" => 2,
+/// };
+/// ```
+#[macro_export]
+macro_rules! chstr_map {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        const KEYS: &[&str] = &[$($key),*];
+        const LEN: usize = KEYS.len();
+        const TABLE: $crate::__private::MapTable<LEN> = $crate::__private::MapTable::new(KEYS);
+        const DISPLACEMENTS: [(u32, u32); LEN] = TABLE.displacements;
+        const SLOTS: [u32; LEN] = TABLE.slots;
+
+        $crate::Map {
+            seed: TABLE.seed,
+            displacements: &DISPLACEMENTS,
+            slots: &SLOTS,
+            keys: KEYS,
+            values: &[$($value),*],
+        }
+    }};
+}
+
+/// An immutable map from `&str` keys to values, built at compile time with
+/// [`chstr_map!`](crate::chstr_map).
+///
+/// Keys and values are stored in the order they are given.
+pub struct Map<V: 'static> {
+    #[doc(hidden)]
+    pub seed: u64,
+    #[doc(hidden)]
+    pub displacements: &'static [(u32, u32)],
+    #[doc(hidden)]
+    pub slots: &'static [u32],
+    #[doc(hidden)]
+    pub keys: &'static [&'static str],
+    #[doc(hidden)]
+    pub values: &'static [V],
+}
+
+impl<V> Map<V> {
+    /// Returns the number of entries in the map.
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns a reference to the value for a key, if it is in the map.
+    pub const fn get(&self, key: &str) -> Option<&V> {
+        match self.index(key) {
+            Some(index) => Some(&self.values[index]),
+            None => None,
+        }
+    }
+
+    /// Returns `true` if the map contains a key.
+    pub const fn contains_key(&self, key: &str) -> bool {
+        self.index(key).is_some()
+    }
+
+    /// Returns the keys of the map.
+    pub const fn keys(&self) -> &'static [&'static str] {
+        self.keys
+    }
+
+    /// Returns the values of the map, in the same order as its keys.
+    pub const fn values(&self) -> &'static [V] {
+        self.values
+    }
+
+    /// Returns the index of the entry for a key, if it is in the map.
+    const fn index(&self, key: &str) -> Option<usize> {
+        if self.keys.is_empty() {
+            return None;
+        }
+
+        let hashes = Hashes::new(key, self.seed);
+        let (d1, d2) = self.displacements[hashes.bucket(self.keys.len())];
+        let index = self.slots[hashes.slot(d1, d2, self.keys.len())] as usize;
+
+        if eq(self.keys[index], key) {
+            Some(index)
+        } else {
+            None
+        }
+    }
+}
+
+/// The number of seeds to try before giving up on building a map.
+const MAX_SEEDS: u64 = 64;
+
+/// A slot that is not yet assigned an entry.
+const EMPTY: u32 = u32::MAX;
+
+/// The hash table for a [`Map`] with `N` entries.
+pub struct MapTable<const N: usize> {
+    pub seed: u64,
+    pub displacements: [(u32, u32); N],
+    pub slots: [u32; N],
+}
+
+impl<const N: usize> MapTable<N> {
+    /// Builds a hash table for the keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys contain duplicates.
+    pub const fn new(keys: &[&str]) -> MapTable<N> {
+        let mut seed = 0;
+        while seed < MAX_SEEDS {
+            if let Some(table) = MapTable::try_new(keys, seed) {
+                return table;
+            }
+            seed += 1;
+        }
+        panic!("failed to find a perfect hash function for the map keys");
+    }
+
+    /// Builds a hash table for the keys with a seed, using the "hash and
+    /// displace" algorithm.
+    ///
+    /// Keys are grouped into buckets, and the buckets are placed from largest to
+    /// smallest, searching for a displacement that moves each key in the bucket
+    /// to a free slot.
+    const fn try_new(keys: &[&str], seed: u64) -> Option<MapTable<N>> {
+        let mut hashes = [Hashes { g: 0, f1: 0, f2: 0 }; N];
+        let mut sizes = [0; N];
+        let mut i = 0;
+        while i < N {
+            hashes[i] = Hashes::new(keys[i], seed);
+            sizes[hashes[i].bucket(N)] += 1;
+            i += 1;
+        }
+
+        // Group the keys by bucket, so that the keys in bucket `b` are
+        // `members[starts[b]..starts[b] + sizes[b]]`.
+        let mut starts = [0; N];
+        let mut b = 1;
+        while b < N {
+            starts[b] = starts[b - 1] + sizes[b - 1];
+            b += 1;
+        }
+        let mut members = [0; N];
+        let mut filled = [0; N];
+        let mut i = 0;
+        while i < N {
+            let bucket = hashes[i].bucket(N);
+            members[starts[bucket] + filled[bucket]] = i;
+            filled[bucket] += 1;
+            i += 1;
+        }
+
+        // Equal keys have equal hashes, so duplicates share a bucket.
+        let mut b = 0;
+        while b < N {
+            let mut i = starts[b];
+            while i < starts[b] + sizes[b] {
+                let mut j = i + 1;
+                while j < starts[b] + sizes[b] {
+                    assert!(
+                        !eq(keys[members[i]], keys[members[j]]),
+                        "duplicate key in map"
+                    );
+                    j += 1;
+                }
+                i += 1;
+            }
+            b += 1;
+        }
+
+        // Sort the non-empty buckets by size, largest first, with a counting
+        // sort. A bucket of size `s` is counted at index `s - 1`.
+        let mut counts = [0; N];
+        let mut b = 0;
+        while b < N {
+            if sizes[b] > 0 {
+                counts[sizes[b] - 1] += 1;
+            }
+            b += 1;
+        }
+        let mut offsets = [0; N];
+        let mut buckets = 0;
+        let mut s = N;
+        while s > 0 {
+            s -= 1;
+            offsets[s] = buckets;
+            buckets += counts[s];
+        }
+        let mut order = [0; N];
+        let mut b = 0;
+        while b < N {
+            if sizes[b] > 0 {
+                order[offsets[sizes[b] - 1]] = b;
+                offsets[sizes[b] - 1] += 1;
+            }
+            b += 1;
+        }
+
+        let mut displacements = [(0, 0); N];
+        let mut slots = [EMPTY; N];
+
+        // Slots claimed by the current attempt, marked with its generation.
+        let mut claimed = [0; N];
+        let mut generation = 0;
+
+        // The next slot that may be free, for placing buckets of one key.
+        let mut free = 0;
+
+        let mut b = 0;
+        while b < buckets {
+            let bucket = order[b];
+            let first = starts[bucket];
+            let last = first + sizes[bucket];
+
+            // A bucket of one key can be displaced straight to a free slot.
+            // These buckets are placed last, so no slots are freed after them.
+            if last - first == 1 {
+                while slots[free] != EMPTY {
+                    free += 1;
+                }
+                let f2 = hashes[members[first]].f2 as usize % N;
+                displacements[bucket] = (0, ((free + N - f2) % N) as u32);
+                slots[free] = members[first] as u32;
+                b += 1;
+                continue;
+            }
+
+            let mut placed = false;
+            let mut d1 = 0;
+            while !placed && d1 < N as u32 {
+                let mut d2 = 0;
+                while !placed && d2 < N as u32 {
+                    generation += 1;
+
+                    let mut fits = true;
+                    let mut i = first;
+                    while fits && i < last {
+                        let slot = hashes[members[i]].slot(d1, d2, N);
+                        if slots[slot] != EMPTY || claimed[slot] == generation {
+                            fits = false;
+                        }
+                        claimed[slot] = generation;
+                        i += 1;
+                    }
+
+                    if fits {
+                        let mut i = first;
+                        while i < last {
+                            slots[hashes[members[i]].slot(d1, d2, N)] = members[i] as u32;
+                            i += 1;
+                        }
+                        displacements[bucket] = (d1, d2);
+                        placed = true;
+                    }
+                    d2 += 1;
+                }
+                d1 += 1;
+            }
+
+            if !placed {
+                return None;
+            }
+            b += 1;
+        }
+
+        Some(MapTable {
+            seed,
+            displacements,
+            slots,
+        })
+    }
+}
+
+/// The hashes of a key, used to select its bucket and slot.
+#[derive(Clone, Copy)]
+struct Hashes {
+    g: u32,
+    f1: u32,
+    f2: u32,
+}
+
+impl Hashes {
+    /// Hashes a key with a seed.
+    const fn new(key: &str, seed: u64) -> Hashes {
+        let hash = hash::xxh64(key.as_bytes(), seed);
+        Hashes {
+            g: (hash >> 42) as u32,
+            f1: ((hash >> 21) & 0x1F_FFFF) as u32,
+            f2: (hash & 0x1F_FFFF) as u32,
+        }
+    }
+
+    /// Returns the bucket of the key in a table of `len` entries.
+    const fn bucket(&self, len: usize) -> usize {
+        self.g as usize % len
+    }
+
+    /// Returns the slot of the key in a table of `len` entries, given the
+    /// displacement of its bucket.
+    const fn slot(&self, d1: u32, d2: u32, len: usize) -> usize {
+        let slot = self.f2 as u64 + self.f1 as u64 * d1 as u64 + d2 as u64;
+        (slot % len as u64) as usize
+    }
+}
+
+/// Returns `true` if two strings are equal.
+const fn eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
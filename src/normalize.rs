@@ -0,0 +1,397 @@
+use crate::buf::Buf;
+use crate::tables::{self, normalize as table};
+use crate::utf8;
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// Unicode Normalization Form C.
+///
+/// Characters are canonically decomposed, combining marks are put in canonical
+/// order, and the result is canonically composed, as described by
+/// [UAX #15](https://www.unicode.org/reports/tr15/).
+///
+/// *Requires the `unicode-normalization` feature.*
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_nfc;
+/// const E: char = 'e';
+/// const ACUTE: char = '\u{301}';
+/// const CAFE: &str = chstr_nfc!["caf", E, ACUTE];
+///
+/// assert_eq!(CAFE, "café");
+/// assert_eq!(chstr_nfc!["\u{1100}\u{1161}\u{11A8}"], "각");
+/// ```
+#[macro_export]
+macro_rules! chstr_nfc {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| {
+            $crate::__private::normalize(buf, STR, $crate::__private::NormalizationForm::Nfc)
+        })
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// Unicode Normalization Form D.
+///
+/// Characters are canonically decomposed and combining marks are put in
+/// canonical order, as described by
+/// [UAX #15](https://www.unicode.org/reports/tr15/).
+///
+/// *Requires the `unicode-normalization` feature.*
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_nfd;
+/// const CAFE: &str = chstr_nfd!["café"];
+///
+/// assert_eq!(CAFE, "cafe\u{301}");
+/// assert_eq!(chstr_nfd!['q', '\u{307}', '\u{323}'], "q\u{323}\u{307}");
+/// ```
+#[macro_export]
+macro_rules! chstr_nfd {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| {
+            $crate::__private::normalize(buf, STR, $crate::__private::NormalizationForm::Nfd)
+        })
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// Unicode Normalization Form KC.
+///
+/// Characters are decomposed by their compatibility mappings, combining marks
+/// are put in canonical order, and the result is canonically composed, as
+/// described by [UAX #15](https://www.unicode.org/reports/tr15/).
+///
+/// *Requires the `unicode-normalization` feature.*
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_nfkc;
+/// const TEXT: &str = chstr_nfkc!["ﬁ ½ Ｘ", 'e', '\u{301}'];
+///
+/// assert_eq!(TEXT, "fi 1⁄2 Xé");
+/// ```
+#[macro_export]
+macro_rules! chstr_nfkc {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| {
+            $crate::__private::normalize(buf, STR, $crate::__private::NormalizationForm::Nfkc)
+        })
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` in
+/// Unicode Normalization Form KD.
+///
+/// Characters are decomposed by their compatibility mappings and combining
+/// marks are put in canonical order, as described by
+/// [UAX #15](https://www.unicode.org/reports/tr15/).
+///
+/// *Requires the `unicode-normalization` feature.*
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_nfkd;
+/// const TEXT: &str = chstr_nfkd!["ﬁ é"];
+///
+/// assert_eq!(TEXT, "fi e\u{301}");
+/// ```
+#[macro_export]
+macro_rules! chstr_nfkd {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| {
+            $crate::__private::normalize(buf, STR, $crate::__private::NormalizationForm::Nfkd)
+        })
+    }};
+}
+
+/// A Unicode normalization form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+/// Writes a string to the buffer in a Unicode normalization form.
+///
+/// # Panics
+///
+/// Panics if the string contains more than 32 combining characters in a row.
+pub const fn normalize<const N: usize>(buf: Buf<N>, s: &str, form: NormalizationForm) -> Buf<N> {
+    let compatibility = matches!(form, NormalizationForm::Nfkc | NormalizationForm::Nfkd);
+    let compose = matches!(form, NormalizationForm::Nfc | NormalizationForm::Nfkc);
+
+    let bytes = s.as_bytes();
+
+    let mut normalizer = Normalizer::new(buf, compose);
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        normalizer = normalizer.decompose(c, compatibility);
+        i += len;
+    }
+    normalizer.finish()
+}
+
+/// The maximum number of characters in a segment.
+const SEGMENT_LEN: usize = 33;
+
+// Hangul syllable constants.
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// Normalizes a stream of characters into a buffer.
+///
+/// Decomposed characters are collected into segments, each of which is a
+/// starter followed by any number of non-starters. A segment is put in
+/// canonical order and composed once the next starter is reached.
+struct Normalizer<const N: usize> {
+    buf: Buf<N>,
+    segment: [char; SEGMENT_LEN],
+    len: usize,
+    compose: bool,
+}
+
+impl<const N: usize> Normalizer<N> {
+    /// Creates a normalizer writing to a buffer.
+    const fn new(buf: Buf<N>, compose: bool) -> Normalizer<N> {
+        Normalizer {
+            buf,
+            segment: ['\0'; SEGMENT_LEN],
+            len: 0,
+            compose,
+        }
+    }
+
+    /// Adds the full decomposition of a character.
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    const fn decompose(self, c: char, compatibility: bool) -> Normalizer<N> {
+        let code = c as u32;
+        if code >= S_BASE && code < S_BASE + S_COUNT {
+            let index = code - S_BASE;
+            // SAFETY: The results are Hangul jamo, which are valid characters.
+            let (l, v, t) = unsafe {
+                (
+                    utf8::char_from_u32_unchecked(L_BASE + index / N_COUNT),
+                    utf8::char_from_u32_unchecked(V_BASE + (index % N_COUNT) / T_COUNT),
+                    utf8::char_from_u32_unchecked(T_BASE + index % T_COUNT),
+                )
+            };
+
+            let normalizer = self.push(l).push(v);
+            return if index % T_COUNT != 0 {
+                normalizer.push(t)
+            } else {
+                normalizer
+            };
+        }
+
+        let mapping = if compatibility {
+            match tables::find(table::COMPATIBILITY_DECOMPOSITION, c) {
+                Some(index) => Some(table::COMPATIBILITY_DECOMPOSITION[index].1),
+                None => None,
+            }
+        } else {
+            None
+        };
+        let mapping = match mapping {
+            Some(mapping) => Some(mapping),
+            None => match tables::find(table::CANONICAL_DECOMPOSITION, c) {
+                Some(index) => Some(table::CANONICAL_DECOMPOSITION[index].1),
+                None => None,
+            },
+        };
+
+        match mapping {
+            Some(mapping) => {
+                let mut normalizer = self;
+                let mut i = 0;
+                while i < mapping.len() {
+                    normalizer = normalizer.push(mapping[i]);
+                    i += 1;
+                }
+                normalizer
+            }
+            None => self.push(c),
+        }
+    }
+
+    /// Adds a decomposed character.
+    const fn push(self, c: char) -> Normalizer<N> {
+        let mut normalizer = self;
+        if combining_class(c) == 0 {
+            normalizer = normalizer.settle();
+
+            // A starter may compose with a preceding starter.
+            if normalizer.compose
+                && normalizer.len == 1
+                && combining_class(normalizer.segment[0]) == 0
+            {
+                if let Some(composed) = compose(normalizer.segment[0], c) {
+                    normalizer.segment[0] = composed;
+                    return normalizer;
+                }
+            }
+
+            normalizer = normalizer.flush();
+        } else {
+            assert!(
+                normalizer.len < SEGMENT_LEN,
+                "too many combining characters in a row to normalize"
+            );
+        }
+
+        normalizer.segment[normalizer.len] = c;
+        normalizer.len += 1;
+        normalizer
+    }
+
+    /// Puts the segment in canonical order and, if composing, composes it.
+    const fn settle(self) -> Normalizer<N> {
+        let mut normalizer = self;
+        let len = normalizer.len;
+
+        // Stable insertion sort by combining class.
+        let mut i = 1;
+        while i < len {
+            let c = normalizer.segment[i];
+            let class = combining_class(c);
+            let mut j = i;
+            while j > 0 && combining_class(normalizer.segment[j - 1]) > class {
+                normalizer.segment[j] = normalizer.segment[j - 1];
+                j -= 1;
+            }
+            normalizer.segment[j] = c;
+            i += 1;
+        }
+
+        if !normalizer.compose || len == 0 || combining_class(normalizer.segment[0]) != 0 {
+            return normalizer;
+        }
+
+        // Compose the starter with each following character that is not
+        // blocked by an earlier, uncomposed character of the same or a higher
+        // combining class.
+        let mut kept = 1;
+        let mut last_class = 0;
+        let mut i = 1;
+        while i < len {
+            let c = normalizer.segment[i];
+            let class = combining_class(c);
+            let composed = if last_class < class {
+                compose(normalizer.segment[0], c)
+            } else {
+                None
+            };
+            match composed {
+                Some(composed) => normalizer.segment[0] = composed,
+                None => {
+                    normalizer.segment[kept] = c;
+                    kept += 1;
+                    last_class = class;
+                }
+            }
+            i += 1;
+        }
+        normalizer.len = kept;
+        normalizer
+    }
+
+    /// Writes the segment to the buffer and clears it.
+    const fn flush(self) -> Normalizer<N> {
+        let mut normalizer = self;
+        let mut i = 0;
+        while i < normalizer.len {
+            normalizer.buf = normalizer.buf.push_char(normalizer.segment[i]);
+            i += 1;
+        }
+        normalizer.len = 0;
+        normalizer
+    }
+
+    /// Writes any remaining characters and returns the buffer.
+    const fn finish(self) -> Buf<N> {
+        self.settle().flush().buf
+    }
+}
+
+/// Returns the canonical combining class of a character.
+const fn combining_class(c: char) -> u8 {
+    match tables::find_range(table::COMBINING_CLASS, c) {
+        Some(index) => table::COMBINING_CLASS[index].2,
+        None => 0,
+    }
+}
+
+/// Returns the primary composite of two characters, if there is one.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+const fn compose(a: char, b: char) -> Option<char> {
+    let (a_code, b_code) = (a as u32, b as u32);
+
+    // Hangul syllables are composed algorithmically.
+    if a_code >= L_BASE
+        && a_code < L_BASE + L_COUNT
+        && b_code >= V_BASE
+        && b_code < V_BASE + V_COUNT
+    {
+        let index = (a_code - L_BASE) * N_COUNT + (b_code - V_BASE) * T_COUNT;
+        // SAFETY: The result is a Hangul syllable, which is a valid character.
+        return Some(unsafe { utf8::char_from_u32_unchecked(S_BASE + index) });
+    }
+    if a_code >= S_BASE
+        && a_code < S_BASE + S_COUNT
+        && (a_code - S_BASE) % T_COUNT == 0
+        && b_code > T_BASE
+        && b_code < T_BASE + T_COUNT
+    {
+        // SAFETY: The result is a Hangul syllable, which is a valid character.
+        return Some(unsafe { utf8::char_from_u32_unchecked(a_code + (b_code - T_BASE)) });
+    }
+
+    let pairs = match tables::find(table::COMPOSITION, a) {
+        Some(index) => table::COMPOSITION[index].1,
+        None => return None,
+    };
+    let mut i = 0;
+    while i < pairs.len() {
+        if pairs[i].0 == b {
+            return Some(pairs[i].1);
+        }
+        i += 1;
+    }
+    None
+}
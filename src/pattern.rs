@@ -0,0 +1,143 @@
+use crate::tables;
+use crate::utf8;
+
+/// A wrapper used to dispatch on the type of a pattern.
+pub struct Pattern<T>(pub T);
+
+impl Pattern<char> {
+    /// Returns a needle matching the character.
+    pub const fn needle(self) -> Needle<'static> {
+        let (bytes, len) = utf8::encode(self.0);
+        Needle::Char(bytes, len)
+    }
+}
+
+impl<'a> Pattern<&'a str> {
+    /// Returns a needle matching the string slice.
+    pub const fn needle(self) -> Needle<'a> {
+        Needle::Str(self.0)
+    }
+}
+
+/// A substring to search for in a string.
+#[derive(Clone, Copy)]
+pub enum Needle<'a> {
+    /// The UTF-8 encoding of a character, and its length.
+    Char([u8; 4], usize),
+    /// A string slice.
+    Str(&'a str),
+}
+
+impl Needle<'_> {
+    /// Returns the length of the needle in bytes.
+    pub(crate) const fn len(&self) -> usize {
+        match self {
+            Needle::Char(_, len) => *len,
+            Needle::Str(s) => s.len(),
+        }
+    }
+
+    /// Returns `true` if the needle occurs at byte `i` of a string.
+    pub(crate) const fn is_match(&self, bytes: &[u8], i: usize) -> bool {
+        let len = self.len();
+        if i + len > bytes.len() {
+            return false;
+        }
+
+        let mut j = 0;
+        while j < len {
+            let b = match self {
+                Needle::Char(needle, _) => needle[j],
+                Needle::Str(needle) => needle.as_bytes()[j],
+            };
+            if bytes[i + j] != b {
+                return false;
+            }
+            j += 1;
+        }
+        true
+    }
+}
+
+impl Pattern<Whitespace> {
+    /// Returns a set matching whitespace.
+    pub const fn char_set(self) -> CharSet<'static> {
+        CharSet::Whitespace
+    }
+}
+
+impl Pattern<AsciiWhitespace> {
+    /// Returns a set matching ASCII whitespace.
+    pub const fn char_set(self) -> CharSet<'static> {
+        CharSet::AsciiWhitespace
+    }
+}
+
+impl Pattern<char> {
+    /// Returns a set matching the character.
+    pub const fn char_set(self) -> CharSet<'static> {
+        CharSet::Char(self.0)
+    }
+}
+
+impl<'a> Pattern<&'a [char]> {
+    /// Returns a set matching any of the characters.
+    pub const fn char_set(self) -> CharSet<'a> {
+        CharSet::Chars(self.0)
+    }
+}
+
+impl<'a, const N: usize> Pattern<&'a [char; N]> {
+    /// Returns a set matching any of the characters.
+    pub const fn char_set(self) -> CharSet<'a> {
+        CharSet::Chars(self.0)
+    }
+}
+
+/// A pattern matching characters with the Unicode `White_Space` property, as
+/// [`char::is_whitespace`].
+///
+/// [`char::is_whitespace`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_whitespace
+#[derive(Clone, Copy, Debug)]
+pub struct Whitespace;
+
+/// A pattern matching ASCII whitespace characters, as
+/// [`char::is_ascii_whitespace`].
+///
+/// [`char::is_ascii_whitespace`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_ascii_whitespace
+#[derive(Clone, Copy, Debug)]
+pub struct AsciiWhitespace;
+
+/// A set of characters to match in a string.
+#[derive(Clone, Copy)]
+pub enum CharSet<'a> {
+    /// Characters with the `White_Space` property.
+    Whitespace,
+    /// ASCII whitespace characters.
+    AsciiWhitespace,
+    /// A single character.
+    Char(char),
+    /// Any of a list of characters.
+    Chars(&'a [char]),
+}
+
+impl CharSet<'_> {
+    /// Returns `true` if the set contains a character.
+    pub(crate) const fn contains(&self, c: char) -> bool {
+        match self {
+            CharSet::Whitespace => tables::is_whitespace(c),
+            CharSet::AsciiWhitespace => c.is_ascii_whitespace(),
+            CharSet::Char(set) => *set == c,
+            CharSet::Chars(set) => {
+                let mut i = 0;
+                while i < set.len() {
+                    if set[i] == c {
+                        return true;
+                    }
+                    i += 1;
+                }
+                false
+            }
+        }
+    }
+}
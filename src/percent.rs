@@ -0,0 +1,234 @@
+use crate::buf::Buf;
+
+/// Percent-encodes a constant `&str`, producing a constant `&str`.
+///
+/// The second argument is a [`PercentEncodeSet`](crate::PercentEncodeSet)
+/// selecting the bytes to encode. Control characters, non-ASCII characters and
+/// `%` are always encoded, using uppercase hexadecimal digits.
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::{chformat, chstr_percent_encode, PercentEncodeSet};
+/// const NAME: &str = "Café & Bar";
+/// const PATH: &str = chstr_percent_encode!("/menu/{id}", PercentEncodeSet::Path);
+/// const QUERY: &str = chstr_percent_encode!(NAME, PercentEncodeSet::Query);
+/// const URL: &str = chformat!("https://example.com{}?name={}", PATH, QUERY);
+///
+/// assert_eq!(URL, "https://example.com/menu/%7Bid%7D?name=Caf%C3%A9%20%26%20Bar");
+/// assert_eq!(chstr_percent_encode!(NAME, PercentEncodeSet::Form), "Caf%C3%A9+%26+Bar");
+/// ```
+#[macro_export]
+macro_rules! chstr_percent_encode {
+    ($s:expr, $set:expr $(,)?) => {{
+        const STR: &str = $s;
+        const SET: $crate::PercentEncodeSet = $set;
+
+        $crate::__chstr_build!(|buf| $crate::__private::percent_encode(buf, STR, SET))
+    }};
+}
+
+/// Decodes a percent-encoded constant `&str`, producing a constant `&str`.
+///
+/// With the `form` option, `+` is also decoded as a space, as in
+/// `application/x-www-form-urlencoded` data.
+///
+/// A `%` that is not followed by two hexadecimal digits, or a result that is
+/// not valid UTF-8, is a compile-time error.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_percent_decode;
+/// const NAME: &str = chstr_percent_decode!("Caf%C3%A9%20%26%20Bar");
+///
+/// assert_eq!(NAME, "Café & Bar");
+/// assert_eq!(chstr_percent_decode!("a+b%2Bc", form), "a b+c");
+/// ```
+///
+/// A malformed sequence fails to compile:
+/// ```compile_fail
+/// # use chstr::chstr_percent_decode;
+/// const BAD: &str = chstr_percent_decode!("100%");
+/// ```
+///
+/// As does a result that is not valid UTF-8:
+/// ```compile_fail
+/// # use chstr::chstr_percent_decode;
+/// const BAD: &str = chstr_percent_decode!("%C3%28");
+/// ```
+#[macro_export]
+macro_rules! chstr_percent_decode {
+    ($s:expr $(,)?) => {{
+        const STR: &str = $s;
+
+        $crate::__chstr_build!(|buf| $crate::__private::percent_decode(buf, STR, false))
+    }};
+    ($s:expr, form $(,)?) => {{
+        const STR: &str = $s;
+
+        $crate::__chstr_build!(|buf| $crate::__private::percent_decode(buf, STR, true))
+    }};
+}
+
+/// A set of ASCII characters to percent-encode, used by
+/// [`chstr_percent_encode!`].
+///
+/// Every set also encodes control characters, non-ASCII characters and `%`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PercentEncodeSet {
+    /// Encodes space, `"`, `#`, `<`, `>`, `?`, `` ` ``, `{` and `}`, for a URL
+    /// path. Slashes are left unchanged.
+    Path,
+    /// Encodes space, `"`, `#`, `&`, `'`, `+`, `<`, `=` and `>`, for a key or
+    /// value in a URL query.
+    Query,
+    /// Encodes the [`Path`](PercentEncodeSet::Path) set, and `/`, `:`, `;`,
+    /// `=`, `@`, `[`, `\`, `]`, `^` and `|`, for the userinfo of a URL.
+    Userinfo,
+    /// Encodes all characters except ASCII alphanumerics, `*`, `-`, `.` and
+    /// `_`, with space written as `+`, for `application/x-www-form-urlencoded`
+    /// data.
+    Form,
+}
+
+impl PercentEncodeSet {
+    /// Returns `true` if the byte is percent-encoded by this set.
+    const fn contains(self, b: u8) -> bool {
+        if !b.is_ascii() || b.is_ascii_control() || b == b'%' {
+            return true;
+        }
+        match self {
+            PercentEncodeSet::Path => {
+                matches!(
+                    b,
+                    b' ' | b'"' | b'#' | b'<' | b'>' | b'?' | b'`' | b'{' | b'}'
+                )
+            }
+            PercentEncodeSet::Query => {
+                matches!(
+                    b,
+                    b' ' | b'"' | b'#' | b'&' | b'\'' | b'+' | b'<' | b'=' | b'>'
+                )
+            }
+            PercentEncodeSet::Userinfo => {
+                PercentEncodeSet::Path.contains(b)
+                    || matches!(
+                        b,
+                        b'/' | b':' | b';' | b'=' | b'@' | b'[' | b'\\' | b']' | b'^' | b'|'
+                    )
+            }
+            PercentEncodeSet::Form => {
+                !(b.is_ascii_alphanumeric() || matches!(b, b'*' | b'-' | b'.' | b'_'))
+            }
+        }
+    }
+}
+
+/// Writes a string to the buffer, percent-encoding the bytes in a set.
+pub const fn percent_encode<const N: usize>(buf: Buf<N>, s: &str, set: PercentEncodeSet) -> Buf<N> {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+    let form = matches!(set, PercentEncodeSet::Form);
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        buf = if form && b == b' ' {
+            buf.push_byte(b'+')
+        } else if set.contains(b) {
+            buf.push_byte(b'%')
+                .push_byte(HEX_DIGITS[(b >> 4) as usize])
+                .push_byte(HEX_DIGITS[(b & 0xF) as usize])
+        } else {
+            buf.push_byte(b)
+        };
+        i += 1;
+    }
+    buf
+}
+
+/// Writes a percent-decoded string to the buffer.
+///
+/// If `form` is `true`, `+` is decoded as a space.
+///
+/// # Panics
+///
+/// Panics if the string contains a malformed percent-encoded sequence, or if
+/// the decoded bytes are not valid UTF-8.
+pub const fn percent_decode<const N: usize>(buf: Buf<N>, s: &str, form: bool) -> Buf<N> {
+    let bytes = s.as_bytes();
+    validate(bytes, form);
+
+    let mut buf = buf;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (b, next) = next_byte(bytes, i, form);
+        buf = buf.push_byte(b);
+        i = next;
+    }
+    buf
+}
+
+/// Checks that percent-decoding the bytes produces valid UTF-8.
+const fn validate(bytes: &[u8], form: bool) {
+    let mut i = 0;
+    while i < bytes.len() {
+        let (first, next) = next_byte(bytes, i, form);
+        i = next;
+
+        // The number of bytes in the sequence and the range of its second byte.
+        let (width, min, max) = match first {
+            0x00..=0x7F => (1, 0, 0),
+            0xC2..=0xDF => (2, 0x80, 0xBF),
+            0xE0 => (3, 0xA0, 0xBF),
+            0xE1..=0xEC | 0xEE..=0xEF => (3, 0x80, 0xBF),
+            0xED => (3, 0x80, 0x9F),
+            0xF0 => (4, 0x90, 0xBF),
+            0xF1..=0xF3 => (4, 0x80, 0xBF),
+            0xF4 => (4, 0x80, 0x8F),
+            _ => panic!("percent-decoded string is not valid UTF-8"),
+        };
+
+        let mut k = 1;
+        while k < width {
+            assert!(i < bytes.len(), "percent-decoded string is not valid UTF-8");
+            let (b, next) = next_byte(bytes, i, form);
+            let (min, max) = if k == 1 { (min, max) } else { (0x80, 0xBF) };
+            assert!(
+                min <= b && b <= max,
+                "percent-decoded string is not valid UTF-8"
+            );
+            i = next;
+            k += 1;
+        }
+    }
+}
+
+/// Decodes the byte starting at index `i`, returning the byte and the index of
+/// the next one.
+const fn next_byte(bytes: &[u8], i: usize, form: bool) -> (u8, usize) {
+    match bytes[i] {
+        b'%' => {
+            assert!(i + 2 < bytes.len(), "invalid percent-encoded sequence");
+            let hi = hex_digit(bytes[i + 1]);
+            let lo = hex_digit(bytes[i + 2]);
+            ((hi << 4) | lo, i + 3)
+        }
+        b'+' if form => (b' ', i + 1),
+        b => (b, i + 1),
+    }
+}
+
+/// Returns the value of a hexadecimal digit.
+const fn hex_digit(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        b'A'..=b'F' => b - b'A' + 10,
+        _ => panic!("invalid percent-encoded sequence"),
+    }
+}
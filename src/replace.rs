@@ -0,0 +1,78 @@
+use crate::buf::Buf;
+use crate::pattern::Needle;
+use crate::utf8;
+
+/// Replaces all matches of a pattern in a constant `&str`, producing a
+/// constant `&str`.
+///
+/// The pattern may be a `char` or a `&str`, and the replacement may be any
+/// single [`chstr!`] argument. As with [`str::replace`], an empty pattern
+/// matches before and after every character.
+///
+/// [`str::replace`]: https://doc.rust-lang.org/std/primitive.str.html#method.replace
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// Replacing characters:
+/// ```
+/// # use chstr::chstr_replace;
+/// const PATH: &str = "etc/app/config";
+/// const NATIVE: &str = chstr_replace!(PATH, '/', std::path::MAIN_SEPARATOR);
+///
+/// # #[cfg(not(windows))]
+/// assert_eq!(NATIVE, "etc/app/config");
+/// # #[cfg(windows)]
+/// # assert_eq!(NATIVE, "etc\\app\\config");
+/// ```
+///
+/// Replacing with strings:
+/// ```
+/// # use chstr::chstr_replace;
+/// const TEMPLATE: &str = "Hello, NAME! Goodbye, NAME!";
+///
+/// assert_eq!(chstr_replace!(TEMPLATE, "NAME", "world"), "Hello, world! Goodbye, world!");
+/// assert_eq!(chstr_replace!("a-b-c", '-', " - "), "a - b - c");
+/// assert_eq!(chstr_replace!("abc", "", '.'), ".a.b.c.");
+/// ```
+#[macro_export]
+macro_rules! chstr_replace {
+    ($s:expr, $from:expr, $to:expr $(,)?) => {{
+        const STR: &str = $s;
+        const FROM: $crate::__private::Needle<'static> = $crate::__private::Pattern($from).needle();
+        const TO: &str = $crate::chstr![$to];
+
+        $crate::__chstr_build!(|buf| $crate::__private::replace(buf, STR, FROM, TO))
+    }};
+}
+
+/// Writes a string to the buffer, replacing all matches of a needle.
+pub const fn replace<const N: usize>(buf: Buf<N>, s: &str, from: Needle, to: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut start = 0;
+    let mut i = 0;
+
+    if from.len() == 0 {
+        while i < bytes.len() {
+            buf = buf.push_str(to);
+            let (_, len) = utf8::decode(bytes, i);
+            buf = buf.push_slice(s, i, i + len);
+            i += len;
+        }
+        return buf.push_str(to);
+    }
+
+    while i < bytes.len() {
+        if from.is_match(bytes, i) {
+            buf = buf.push_slice(s, start, i);
+            buf = buf.push_str(to);
+            i += from.len();
+            start = i;
+        } else {
+            i += 1;
+        }
+    }
+    buf.push_slice(s, start, bytes.len())
+}
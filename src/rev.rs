@@ -0,0 +1,114 @@
+use crate::buf::Buf;
+use crate::utf8;
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` with the
+/// characters in reverse order.
+///
+/// Characters are reversed by Unicode scalar value, so combining marks are
+/// moved before the character they modify. See `chstr_rev_graphemes!` to
+/// reverse by grapheme cluster instead.
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_rev;
+/// const WORD: &str = "stressed";
+/// const REVERSED: &str = chstr_rev![WORD];
+///
+/// assert_eq!(REVERSED, "desserts");
+/// assert_eq!(chstr_rev!['a', 'b', "cd", 'é'], "édcba");
+/// ```
+#[macro_export]
+macro_rules! chstr_rev {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        $crate::__chstr_build!(|buf| $crate::__private::reverse(buf, STR))
+    }};
+}
+
+/// Converts a sequence of [`chstr!`] arguments into a constant `&str` with the
+/// extended grapheme clusters in reverse order.
+///
+/// Grapheme clusters are segmented as described by
+/// [UAX #29](https://www.unicode.org/reports/tr29/), so combining marks, emoji
+/// sequences and flags are kept intact.
+///
+/// *Requires the `unicode-segmentation` feature.*
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_rev_graphemes;
+/// const WORD: &str = "cafe\u{301}";
+/// const REVERSED: &str = chstr_rev_graphemes![WORD, " 🇳🇿👩‍👩‍👧"];
+///
+/// assert_eq!(REVERSED, "👩‍👩‍👧🇳🇿 e\u{301}fac");
+/// ```
+#[cfg(feature = "unicode-segmentation")]
+#[macro_export]
+macro_rules! chstr_rev_graphemes {
+    [$($arg:expr),* $(,)?] => {{
+        const STR: &str = $crate::chstr![$($arg),*];
+
+        const COUNT: usize = $crate::__private::grapheme_starts::<0>(STR).1;
+        const STARTS: [usize; COUNT] = $crate::__private::grapheme_starts::<COUNT>(STR).0;
+
+        $crate::__chstr_build!(|buf| $crate::__private::reverse_graphemes(buf, STR, &STARTS))
+    }};
+}
+
+/// Writes the characters of a string to the buffer in reverse order.
+pub const fn reverse<const N: usize>(buf: Buf<N>, s: &str) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut buf = buf;
+    let mut end = bytes.len();
+    while end > 0 {
+        let (_, len) = utf8::decode_last(bytes, end);
+        buf = buf.push_slice(s, end - len, end);
+        end -= len;
+    }
+    buf
+}
+
+/// Returns the byte indices of the starts of up to `N` extended grapheme
+/// clusters of a string, and the total number of grapheme clusters.
+#[cfg(feature = "unicode-segmentation")]
+pub const fn grapheme_starts<const N: usize>(s: &str) -> ([usize; N], usize) {
+    use crate::grapheme;
+
+    let bytes = s.as_bytes();
+
+    let mut starts = [0; N];
+    let mut count = 0;
+    let mut start = 0;
+    while start < bytes.len() {
+        if count < N {
+            starts[count] = start;
+        }
+        count += 1;
+        start = grapheme::next_boundary(bytes, start);
+    }
+    (starts, count)
+}
+
+/// Writes the extended grapheme clusters of a string to the buffer in reverse
+/// order, given the byte indices of their starts.
+#[cfg(feature = "unicode-segmentation")]
+pub const fn reverse_graphemes<const N: usize>(buf: Buf<N>, s: &str, starts: &[usize]) -> Buf<N> {
+    let mut buf = buf;
+    let mut end = s.len();
+    let mut i = starts.len();
+    while i > 0 {
+        i -= 1;
+        buf = buf.push_slice(s, starts[i], end);
+        end = starts[i];
+    }
+    buf
+}
@@ -0,0 +1,250 @@
+use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::buf::Buf;
+use crate::utf8;
+
+/// Copies a range of characters from a constant `&str` into a constant `&str`.
+///
+/// The range is given in characters, not bytes, and may be any of the range
+/// types, such as `2..5`, `..8` or `3..`. A range that is out of bounds is a
+/// compile-time error.
+///
+/// See [`chstr_byte_slice!`] to slice by byte index.
+///
+/// [`chstr_byte_slice!`]: crate::chstr_byte_slice
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_slice;
+/// const HASH: &str = "3f786850e387550fdab836ed7e6dc881de23001b";
+/// const SHORT_HASH: &str = chstr_slice!(HASH, ..8);
+///
+/// assert_eq!(SHORT_HASH, "3f786850");
+/// ```
+///
+/// Indices count characters:
+/// ```
+/// # use chstr::chstr_slice;
+/// const GREETING: &str = "¡Hola, señor!";
+///
+/// assert_eq!(chstr_slice!(GREETING, 1..5), "Hola");
+/// assert_eq!(chstr_slice!(GREETING, 7..=11), "señor");
+/// assert_eq!(chstr_slice!(GREETING, 12..), "!");
+/// assert_eq!(chstr_slice!(GREETING, ..), GREETING);
+/// ```
+///
+/// Out of bounds ranges fail to compile:
+/// ```compile_fail
+/// # use chstr::chstr_slice;
+/// const BAD: &str = chstr_slice!("abc", 2..4);
+/// ```
+#[macro_export]
+macro_rules! chstr_slice {
+    ($s:expr, $range:expr $(,)?) => {{
+        const STR: &str = $s;
+        const BOUNDS: (usize, ::core::option::Option<usize>) =
+            $crate::__private::Bounds($range).get();
+
+        $crate::__chstr_build!(|buf| $crate::__private::slice_chars(buf, STR, BOUNDS))
+    }};
+}
+
+/// Copies a range of bytes from a constant `&str` into a constant `&str`.
+///
+/// The range may be any of the range types, such as `2..5`, `..8` or `3..`.
+/// A range that is out of bounds, or does not start and end on character
+/// boundaries, is a compile-time error.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_byte_slice;
+/// const GREETING: &str = "¡Hola!";
+/// const HOLA: &str = chstr_byte_slice!(GREETING, 2..6);
+///
+/// assert_eq!(HOLA, "Hola");
+/// ```
+///
+/// Ranges inside a character fail to compile:
+/// ```compile_fail
+/// # use chstr::chstr_byte_slice;
+/// const BAD: &str = chstr_byte_slice!("¡Hola!", 1..6);
+/// ```
+#[macro_export]
+macro_rules! chstr_byte_slice {
+    ($s:expr, $range:expr $(,)?) => {{
+        const STR: &str = $s;
+        const BOUNDS: (usize, ::core::option::Option<usize>) =
+            $crate::__private::Bounds($range).get();
+
+        $crate::__chstr_build!(|buf| $crate::__private::slice_bytes(buf, STR, BOUNDS))
+    }};
+}
+
+/// Returns the number of characters in a string.
+///
+/// # Examples
+///
+/// ```
+/// const GREETING: &str = "¡Hola!";
+/// const LEN: usize = chstr::char_count(GREETING);
+///
+/// assert_eq!(LEN, 6);
+/// ```
+pub const fn char_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if is_char_boundary(bytes, i) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the character at a character index of a string.
+///
+/// # Panics
+///
+/// Panics if `index` is not less than the number of characters in the string.
+///
+/// # Examples
+///
+/// ```
+/// const GREETING: &str = "¡Hola!";
+/// const FIRST: char = chstr::char_at(GREETING, 0);
+///
+/// assert_eq!(FIRST, '¡');
+/// assert_eq!(chstr::char_at(GREETING, 5), '!');
+/// ```
+pub const fn char_at(s: &str, index: usize) -> char {
+    let bytes = s.as_bytes();
+
+    let i = byte_index(bytes, index);
+    assert!(i < bytes.len(), "char index is out of bounds");
+    utf8::decode(bytes, i).0
+}
+
+/// A wrapper used to dispatch on the type of a range.
+pub struct Bounds<T>(pub T);
+
+impl Bounds<Range<usize>> {
+    /// Returns the start and end of the range.
+    pub const fn get(self) -> (usize, Option<usize>) {
+        (self.0.start, Some(self.0.end))
+    }
+}
+
+impl Bounds<RangeInclusive<usize>> {
+    /// Returns the start and exclusive end of the range.
+    pub const fn get(self) -> (usize, Option<usize>) {
+        assert!(*self.0.end() != usize::MAX, "range end is out of bounds");
+        (*self.0.start(), Some(*self.0.end() + 1))
+    }
+}
+
+impl Bounds<RangeFrom<usize>> {
+    /// Returns the start of the range.
+    pub const fn get(self) -> (usize, Option<usize>) {
+        (self.0.start, None)
+    }
+}
+
+impl Bounds<RangeTo<usize>> {
+    /// Returns the end of the range.
+    pub const fn get(self) -> (usize, Option<usize>) {
+        (0, Some(self.0.end))
+    }
+}
+
+impl Bounds<RangeToInclusive<usize>> {
+    /// Returns the exclusive end of the range.
+    pub const fn get(self) -> (usize, Option<usize>) {
+        assert!(self.0.end != usize::MAX, "range end is out of bounds");
+        (0, Some(self.0.end + 1))
+    }
+}
+
+impl Bounds<RangeFull> {
+    /// Returns an unbounded range.
+    pub const fn get(self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// Writes a range of characters of a string to the buffer.
+pub const fn slice_chars<const N: usize>(
+    buf: Buf<N>,
+    s: &str,
+    bounds: (usize, Option<usize>),
+) -> Buf<N> {
+    let bytes = s.as_bytes();
+    let (start, end) = bounds;
+
+    let start = byte_index(bytes, start);
+    let end = match end {
+        Some(end) => byte_index(bytes, end),
+        None => bytes.len(),
+    };
+    assert!(
+        start <= bytes.len() && end <= bytes.len(),
+        "char index is out of bounds"
+    );
+    assert!(start <= end, "slice index starts after its end");
+
+    buf.push_slice(s, start, end)
+}
+
+/// Writes a range of bytes of a string to the buffer.
+pub const fn slice_bytes<const N: usize>(
+    buf: Buf<N>,
+    s: &str,
+    bounds: (usize, Option<usize>),
+) -> Buf<N> {
+    let bytes = s.as_bytes();
+    let (start, end) = bounds;
+
+    let end = match end {
+        Some(end) => end,
+        None => bytes.len(),
+    };
+    assert!(
+        start <= bytes.len() && end <= bytes.len(),
+        "byte index is out of bounds"
+    );
+    assert!(start <= end, "slice index starts after its end");
+    assert!(
+        is_char_boundary(bytes, start) && is_char_boundary(bytes, end),
+        "byte index is not a char boundary"
+    );
+
+    buf.push_slice(s, start, end)
+}
+
+/// Returns the byte index of a character index in a string.
+///
+/// If the string has fewer characters than `index`, the result is greater than
+/// the length of the string.
+const fn byte_index(bytes: &[u8], index: usize) -> usize {
+    let mut i = 0;
+    let mut n = 0;
+    while n < index {
+        if i >= bytes.len() {
+            return bytes.len() + 1;
+        }
+        i += utf8::decode(bytes, i).1;
+        n += 1;
+    }
+    i
+}
+
+/// Returns `true` if byte `i` of a string is the start or end of a character.
+const fn is_char_boundary(bytes: &[u8], i: usize) -> bool {
+    i == bytes.len() || bytes[i] & 0b1100_0000 != 0b1000_0000
+}
@@ -0,0 +1,178 @@
+use crate::pattern::Needle;
+use crate::utf8;
+
+/// Splits a constant `&str` by a delimiter into a constant `&[&str]`.
+///
+/// The delimiter may be a `char` or a `&str`. The pieces borrow from the input,
+/// without copying it. As with [`str::split`], an empty delimiter matches
+/// before and after every character.
+///
+/// Options may follow the delimiter:
+///
+/// - `skip_empty` removes empty pieces from the result.
+/// - `limit = n` splits into at most `n` pieces, with the last piece containing
+///   the rest of the input, as with [`str::splitn`]. The limit is applied
+///   before empty pieces are removed.
+///
+/// *Compiler support: requires rustc 1.64+*
+///
+/// [`str::split`]: https://doc.rust-lang.org/std/primitive.str.html#method.split
+/// [`str::splitn`]: https://doc.rust-lang.org/std/primitive.str.html#method.splitn
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_split;
+/// const FEATURES: &str = "a,b,,c";
+/// const LIST: &[&str] = chstr_split!(FEATURES, ',');
+///
+/// assert_eq!(LIST, ["a", "b", "", "c"]);
+/// ```
+///
+/// Options:
+/// ```
+/// # use chstr::chstr_split;
+/// const PATH: &str = "/usr//local/bin/";
+///
+/// assert_eq!(chstr_split!(PATH, '/', skip_empty), ["usr", "local", "bin"]);
+/// assert_eq!(chstr_split!(PATH, '/', limit = 3), ["", "usr", "/local/bin/"]);
+/// assert_eq!(chstr_split!("k = v = w", " = ", limit = 2), ["k", "v = w"]);
+/// ```
+#[macro_export]
+macro_rules! chstr_split {
+    ($s:expr, $delim:expr $(, $opt:ident $(= $value:expr)?)* $(,)?) => {{
+        const STR: &str = $s;
+        const DELIM: $crate::__private::Needle<'static> =
+            $crate::__private::Pattern($delim).needle();
+        const OPTIONS: $crate::__private::SplitOptions =
+            $crate::__private::SplitOptions::new()$(.$opt($($value)?))*;
+
+        const LEN: usize = $crate::__private::split::<0>(STR, DELIM, OPTIONS).1;
+
+        const PIECES: [&str; LEN] = {
+            let (ranges, _) = $crate::__private::split::<LEN>(STR, DELIM, OPTIONS);
+
+            let mut pieces = [""; LEN];
+            let mut i = 0;
+            while i < LEN {
+                let (start, end) = ranges[i];
+                // SAFETY: The range is within the string and lies on character
+                //         boundaries.
+                pieces[i] = unsafe {
+                    ::core::str::from_utf8_unchecked(::core::slice::from_raw_parts(
+                        STR.as_ptr().add(start),
+                        end - start,
+                    ))
+                };
+                i += 1;
+            }
+            pieces
+        };
+
+        &PIECES as &[&str]
+    }};
+}
+
+/// Options for splitting a string.
+#[derive(Clone, Copy)]
+pub struct SplitOptions {
+    skip_empty: bool,
+    limit: usize,
+}
+
+impl SplitOptions {
+    /// Returns the default options.
+    pub const fn new() -> SplitOptions {
+        SplitOptions {
+            skip_empty: false,
+            limit: usize::MAX,
+        }
+    }
+
+    /// Removes empty pieces from the result.
+    pub const fn skip_empty(mut self) -> SplitOptions {
+        self.skip_empty = true;
+        self
+    }
+
+    /// Splits into at most `limit` pieces.
+    pub const fn limit(mut self, limit: usize) -> SplitOptions {
+        self.limit = limit;
+        self
+    }
+}
+
+impl Default for SplitOptions {
+    fn default() -> SplitOptions {
+        SplitOptions::new()
+    }
+}
+
+/// Splits a string by a delimiter, returning the byte ranges of up to `N`
+/// pieces and the total number of pieces.
+pub const fn split<const N: usize>(
+    s: &str,
+    delim: Needle,
+    options: SplitOptions,
+) -> ([(usize, usize); N], usize) {
+    let bytes = s.as_bytes();
+
+    let mut ranges = [(0, 0); N];
+    let mut count = 0;
+    let mut pieces = 0;
+
+    let mut start = 0;
+    let mut pos = 0;
+    while pieces < options.limit {
+        pieces += 1;
+
+        let end = if pieces == options.limit {
+            None
+        } else {
+            find(bytes, delim, pos)
+        };
+        let (piece_end, next_start) = match end {
+            Some(end) => (end, end + delim.len()),
+            None => (bytes.len(), bytes.len()),
+        };
+
+        if !options.skip_empty || piece_end > start {
+            if count < N {
+                ranges[count] = (start, piece_end);
+            }
+            count += 1;
+        }
+
+        if end.is_none() {
+            break;
+        }
+
+        // An empty delimiter matches after each character.
+        pos = if delim.len() == 0 {
+            if next_start == bytes.len() {
+                bytes.len() + 1
+            } else {
+                next_start + utf8::decode(bytes, next_start).1
+            }
+        } else {
+            next_start
+        };
+        start = next_start;
+    }
+
+    (ranges, count)
+}
+
+/// Returns the byte index of the first match of a needle at or after byte
+/// `from` of a string.
+const fn find(bytes: &[u8], needle: Needle, from: usize) -> Option<usize> {
+    let mut i = from;
+    while i + needle.len() <= bytes.len() {
+        if needle.is_match(bytes, i) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
@@ -0,0 +1,1446 @@
+// This file is generated by `scripts/unicode.py`. Do not edit it by hand.
+//
+// Unicode version: 14.0.0
+
+use crate::grapheme::GraphemeClusterBreak;
+
+/// Characters with a `Grapheme_Cluster_Break` property other than `Other`.
+pub(crate) const GRAPHEME_CLUSTER_BREAK: &[(char, char, GraphemeClusterBreak)] = &[
+    ('\u{0}', '\u{9}', GraphemeClusterBreak::Control),
+    ('\u{A}', '\u{A}', GraphemeClusterBreak::LF),
+    ('\u{B}', '\u{C}', GraphemeClusterBreak::Control),
+    ('\u{D}', '\u{D}', GraphemeClusterBreak::CR),
+    ('\u{E}', '\u{1F}', GraphemeClusterBreak::Control),
+    ('\u{7F}', '\u{9F}', GraphemeClusterBreak::Control),
+    ('\u{AD}', '\u{AD}', GraphemeClusterBreak::Control),
+    ('\u{300}', '\u{36F}', GraphemeClusterBreak::Extend),
+    ('\u{483}', '\u{489}', GraphemeClusterBreak::Extend),
+    ('\u{591}', '\u{5BD}', GraphemeClusterBreak::Extend),
+    ('\u{5BF}', '\u{5BF}', GraphemeClusterBreak::Extend),
+    ('\u{5C1}', '\u{5C2}', GraphemeClusterBreak::Extend),
+    ('\u{5C4}', '\u{5C5}', GraphemeClusterBreak::Extend),
+    ('\u{5C7}', '\u{5C7}', GraphemeClusterBreak::Extend),
+    ('\u{600}', '\u{605}', GraphemeClusterBreak::Prepend),
+    ('\u{610}', '\u{61A}', GraphemeClusterBreak::Extend),
+    ('\u{61C}', '\u{61C}', GraphemeClusterBreak::Control),
+    ('\u{64B}', '\u{65F}', GraphemeClusterBreak::Extend),
+    ('\u{670}', '\u{670}', GraphemeClusterBreak::Extend),
+    ('\u{6D6}', '\u{6DC}', GraphemeClusterBreak::Extend),
+    ('\u{6DD}', '\u{6DD}', GraphemeClusterBreak::Prepend),
+    ('\u{6DF}', '\u{6E4}', GraphemeClusterBreak::Extend),
+    ('\u{6E7}', '\u{6E8}', GraphemeClusterBreak::Extend),
+    ('\u{6EA}', '\u{6ED}', GraphemeClusterBreak::Extend),
+    ('\u{70F}', '\u{70F}', GraphemeClusterBreak::Prepend),
+    ('\u{711}', '\u{711}', GraphemeClusterBreak::Extend),
+    ('\u{730}', '\u{74A}', GraphemeClusterBreak::Extend),
+    ('\u{7A6}', '\u{7B0}', GraphemeClusterBreak::Extend),
+    ('\u{7EB}', '\u{7F3}', GraphemeClusterBreak::Extend),
+    ('\u{7FD}', '\u{7FD}', GraphemeClusterBreak::Extend),
+    ('\u{816}', '\u{819}', GraphemeClusterBreak::Extend),
+    ('\u{81B}', '\u{823}', GraphemeClusterBreak::Extend),
+    ('\u{825}', '\u{827}', GraphemeClusterBreak::Extend),
+    ('\u{829}', '\u{82D}', GraphemeClusterBreak::Extend),
+    ('\u{859}', '\u{85B}', GraphemeClusterBreak::Extend),
+    ('\u{890}', '\u{891}', GraphemeClusterBreak::Prepend),
+    ('\u{898}', '\u{89F}', GraphemeClusterBreak::Extend),
+    ('\u{8CA}', '\u{8E1}', GraphemeClusterBreak::Extend),
+    ('\u{8E2}', '\u{8E2}', GraphemeClusterBreak::Prepend),
+    ('\u{8E3}', '\u{902}', GraphemeClusterBreak::Extend),
+    ('\u{903}', '\u{903}', GraphemeClusterBreak::SpacingMark),
+    ('\u{93A}', '\u{93A}', GraphemeClusterBreak::Extend),
+    ('\u{93B}', '\u{93B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{93C}', '\u{93C}', GraphemeClusterBreak::Extend),
+    ('\u{93E}', '\u{940}', GraphemeClusterBreak::SpacingMark),
+    ('\u{941}', '\u{948}', GraphemeClusterBreak::Extend),
+    ('\u{949}', '\u{94C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{94D}', '\u{94D}', GraphemeClusterBreak::Extend),
+    ('\u{94E}', '\u{94F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{951}', '\u{957}', GraphemeClusterBreak::Extend),
+    ('\u{962}', '\u{963}', GraphemeClusterBreak::Extend),
+    ('\u{981}', '\u{981}', GraphemeClusterBreak::Extend),
+    ('\u{982}', '\u{983}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9BC}', '\u{9BC}', GraphemeClusterBreak::Extend),
+    ('\u{9BE}', '\u{9BE}', GraphemeClusterBreak::Extend),
+    ('\u{9BF}', '\u{9C0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9C1}', '\u{9C4}', GraphemeClusterBreak::Extend),
+    ('\u{9C7}', '\u{9C8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9CB}', '\u{9CC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{9CD}', '\u{9CD}', GraphemeClusterBreak::Extend),
+    ('\u{9D7}', '\u{9D7}', GraphemeClusterBreak::Extend),
+    ('\u{9E2}', '\u{9E3}', GraphemeClusterBreak::Extend),
+    ('\u{9FE}', '\u{9FE}', GraphemeClusterBreak::Extend),
+    ('\u{A01}', '\u{A02}', GraphemeClusterBreak::Extend),
+    ('\u{A03}', '\u{A03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A3C}', '\u{A3C}', GraphemeClusterBreak::Extend),
+    ('\u{A3E}', '\u{A40}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A41}', '\u{A42}', GraphemeClusterBreak::Extend),
+    ('\u{A47}', '\u{A48}', GraphemeClusterBreak::Extend),
+    ('\u{A4B}', '\u{A4D}', GraphemeClusterBreak::Extend),
+    ('\u{A51}', '\u{A51}', GraphemeClusterBreak::Extend),
+    ('\u{A70}', '\u{A71}', GraphemeClusterBreak::Extend),
+    ('\u{A75}', '\u{A75}', GraphemeClusterBreak::Extend),
+    ('\u{A81}', '\u{A82}', GraphemeClusterBreak::Extend),
+    ('\u{A83}', '\u{A83}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABC}', '\u{ABC}', GraphemeClusterBreak::Extend),
+    ('\u{ABE}', '\u{AC0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AC1}', '\u{AC5}', GraphemeClusterBreak::Extend),
+    ('\u{AC7}', '\u{AC8}', GraphemeClusterBreak::Extend),
+    ('\u{AC9}', '\u{AC9}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ACB}', '\u{ACC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ACD}', '\u{ACD}', GraphemeClusterBreak::Extend),
+    ('\u{AE2}', '\u{AE3}', GraphemeClusterBreak::Extend),
+    ('\u{AFA}', '\u{AFF}', GraphemeClusterBreak::Extend),
+    ('\u{B01}', '\u{B01}', GraphemeClusterBreak::Extend),
+    ('\u{B02}', '\u{B03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B3C}', '\u{B3C}', GraphemeClusterBreak::Extend),
+    ('\u{B3E}', '\u{B3F}', GraphemeClusterBreak::Extend),
+    ('\u{B40}', '\u{B40}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B41}', '\u{B44}', GraphemeClusterBreak::Extend),
+    ('\u{B47}', '\u{B48}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B4B}', '\u{B4C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{B4D}', '\u{B4D}', GraphemeClusterBreak::Extend),
+    ('\u{B55}', '\u{B57}', GraphemeClusterBreak::Extend),
+    ('\u{B62}', '\u{B63}', GraphemeClusterBreak::Extend),
+    ('\u{B82}', '\u{B82}', GraphemeClusterBreak::Extend),
+    ('\u{BBE}', '\u{BBE}', GraphemeClusterBreak::Extend),
+    ('\u{BBF}', '\u{BBF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BC0}', '\u{BC0}', GraphemeClusterBreak::Extend),
+    ('\u{BC1}', '\u{BC2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BC6}', '\u{BC8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BCA}', '\u{BCC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{BCD}', '\u{BCD}', GraphemeClusterBreak::Extend),
+    ('\u{BD7}', '\u{BD7}', GraphemeClusterBreak::Extend),
+    ('\u{C00}', '\u{C00}', GraphemeClusterBreak::Extend),
+    ('\u{C01}', '\u{C03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{C04}', '\u{C04}', GraphemeClusterBreak::Extend),
+    ('\u{C3C}', '\u{C3C}', GraphemeClusterBreak::Extend),
+    ('\u{C3E}', '\u{C40}', GraphemeClusterBreak::Extend),
+    ('\u{C41}', '\u{C44}', GraphemeClusterBreak::SpacingMark),
+    ('\u{C46}', '\u{C48}', GraphemeClusterBreak::Extend),
+    ('\u{C4A}', '\u{C4D}', GraphemeClusterBreak::Extend),
+    ('\u{C55}', '\u{C56}', GraphemeClusterBreak::Extend),
+    ('\u{C62}', '\u{C63}', GraphemeClusterBreak::Extend),
+    ('\u{C81}', '\u{C81}', GraphemeClusterBreak::Extend),
+    ('\u{C82}', '\u{C83}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CBC}', '\u{CBC}', GraphemeClusterBreak::Extend),
+    ('\u{CBE}', '\u{CBE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CBF}', '\u{CBF}', GraphemeClusterBreak::Extend),
+    ('\u{CC0}', '\u{CC1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CC2}', '\u{CC2}', GraphemeClusterBreak::Extend),
+    ('\u{CC3}', '\u{CC4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CC6}', '\u{CC6}', GraphemeClusterBreak::Extend),
+    ('\u{CC7}', '\u{CC8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CCA}', '\u{CCB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{CCC}', '\u{CCD}', GraphemeClusterBreak::Extend),
+    ('\u{CD5}', '\u{CD6}', GraphemeClusterBreak::Extend),
+    ('\u{CE2}', '\u{CE3}', GraphemeClusterBreak::Extend),
+    ('\u{D00}', '\u{D01}', GraphemeClusterBreak::Extend),
+    ('\u{D02}', '\u{D03}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D3B}', '\u{D3C}', GraphemeClusterBreak::Extend),
+    ('\u{D3E}', '\u{D3E}', GraphemeClusterBreak::Extend),
+    ('\u{D3F}', '\u{D40}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D41}', '\u{D44}', GraphemeClusterBreak::Extend),
+    ('\u{D46}', '\u{D48}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D4A}', '\u{D4C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{D4D}', '\u{D4D}', GraphemeClusterBreak::Extend),
+    ('\u{D4E}', '\u{D4E}', GraphemeClusterBreak::Prepend),
+    ('\u{D57}', '\u{D57}', GraphemeClusterBreak::Extend),
+    ('\u{D62}', '\u{D63}', GraphemeClusterBreak::Extend),
+    ('\u{D81}', '\u{D81}', GraphemeClusterBreak::Extend),
+    ('\u{D82}', '\u{D83}', GraphemeClusterBreak::SpacingMark),
+    ('\u{DCA}', '\u{DCA}', GraphemeClusterBreak::Extend),
+    ('\u{DCF}', '\u{DCF}', GraphemeClusterBreak::Extend),
+    ('\u{DD0}', '\u{DD1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{DD2}', '\u{DD4}', GraphemeClusterBreak::Extend),
+    ('\u{DD6}', '\u{DD6}', GraphemeClusterBreak::Extend),
+    ('\u{DD8}', '\u{DDE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{DDF}', '\u{DDF}', GraphemeClusterBreak::Extend),
+    ('\u{DF2}', '\u{DF3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{E31}', '\u{E31}', GraphemeClusterBreak::Extend),
+    ('\u{E33}', '\u{E33}', GraphemeClusterBreak::SpacingMark),
+    ('\u{E34}', '\u{E3A}', GraphemeClusterBreak::Extend),
+    ('\u{E47}', '\u{E4E}', GraphemeClusterBreak::Extend),
+    ('\u{EB1}', '\u{EB1}', GraphemeClusterBreak::Extend),
+    ('\u{EB3}', '\u{EB3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{EB4}', '\u{EBC}', GraphemeClusterBreak::Extend),
+    ('\u{EC8}', '\u{ECD}', GraphemeClusterBreak::Extend),
+    ('\u{F18}', '\u{F19}', GraphemeClusterBreak::Extend),
+    ('\u{F35}', '\u{F35}', GraphemeClusterBreak::Extend),
+    ('\u{F37}', '\u{F37}', GraphemeClusterBreak::Extend),
+    ('\u{F39}', '\u{F39}', GraphemeClusterBreak::Extend),
+    ('\u{F3E}', '\u{F3F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{F71}', '\u{F7E}', GraphemeClusterBreak::Extend),
+    ('\u{F7F}', '\u{F7F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{F80}', '\u{F84}', GraphemeClusterBreak::Extend),
+    ('\u{F86}', '\u{F87}', GraphemeClusterBreak::Extend),
+    ('\u{F8D}', '\u{F97}', GraphemeClusterBreak::Extend),
+    ('\u{F99}', '\u{FBC}', GraphemeClusterBreak::Extend),
+    ('\u{FC6}', '\u{FC6}', GraphemeClusterBreak::Extend),
+    ('\u{102D}', '\u{1030}', GraphemeClusterBreak::Extend),
+    ('\u{1031}', '\u{1031}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1032}', '\u{1037}', GraphemeClusterBreak::Extend),
+    ('\u{1039}', '\u{103A}', GraphemeClusterBreak::Extend),
+    ('\u{103B}', '\u{103C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{103D}', '\u{103E}', GraphemeClusterBreak::Extend),
+    ('\u{1056}', '\u{1057}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1058}', '\u{1059}', GraphemeClusterBreak::Extend),
+    ('\u{105E}', '\u{1060}', GraphemeClusterBreak::Extend),
+    ('\u{1071}', '\u{1074}', GraphemeClusterBreak::Extend),
+    ('\u{1082}', '\u{1082}', GraphemeClusterBreak::Extend),
+    ('\u{1084}', '\u{1084}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1085}', '\u{1086}', GraphemeClusterBreak::Extend),
+    ('\u{108D}', '\u{108D}', GraphemeClusterBreak::Extend),
+    ('\u{109D}', '\u{109D}', GraphemeClusterBreak::Extend),
+    ('\u{1100}', '\u{115F}', GraphemeClusterBreak::L),
+    ('\u{1160}', '\u{11A7}', GraphemeClusterBreak::V),
+    ('\u{11A8}', '\u{11FF}', GraphemeClusterBreak::T),
+    ('\u{135D}', '\u{135F}', GraphemeClusterBreak::Extend),
+    ('\u{1712}', '\u{1714}', GraphemeClusterBreak::Extend),
+    ('\u{1715}', '\u{1715}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1732}', '\u{1733}', GraphemeClusterBreak::Extend),
+    ('\u{1734}', '\u{1734}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1752}', '\u{1753}', GraphemeClusterBreak::Extend),
+    ('\u{1772}', '\u{1773}', GraphemeClusterBreak::Extend),
+    ('\u{17B4}', '\u{17B5}', GraphemeClusterBreak::Extend),
+    ('\u{17B6}', '\u{17B6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17B7}', '\u{17BD}', GraphemeClusterBreak::Extend),
+    ('\u{17BE}', '\u{17C5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17C6}', '\u{17C6}', GraphemeClusterBreak::Extend),
+    ('\u{17C7}', '\u{17C8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{17C9}', '\u{17D3}', GraphemeClusterBreak::Extend),
+    ('\u{17DD}', '\u{17DD}', GraphemeClusterBreak::Extend),
+    ('\u{180B}', '\u{180D}', GraphemeClusterBreak::Extend),
+    ('\u{180E}', '\u{180E}', GraphemeClusterBreak::Control),
+    ('\u{180F}', '\u{180F}', GraphemeClusterBreak::Extend),
+    ('\u{1885}', '\u{1886}', GraphemeClusterBreak::Extend),
+    ('\u{18A9}', '\u{18A9}', GraphemeClusterBreak::Extend),
+    ('\u{1920}', '\u{1922}', GraphemeClusterBreak::Extend),
+    ('\u{1923}', '\u{1926}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1927}', '\u{1928}', GraphemeClusterBreak::Extend),
+    ('\u{1929}', '\u{192B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1930}', '\u{1931}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1932}', '\u{1932}', GraphemeClusterBreak::Extend),
+    ('\u{1933}', '\u{1938}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1939}', '\u{193B}', GraphemeClusterBreak::Extend),
+    ('\u{1A17}', '\u{1A18}', GraphemeClusterBreak::Extend),
+    ('\u{1A19}', '\u{1A1A}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A1B}', '\u{1A1B}', GraphemeClusterBreak::Extend),
+    ('\u{1A55}', '\u{1A55}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A56}', '\u{1A56}', GraphemeClusterBreak::Extend),
+    ('\u{1A57}', '\u{1A57}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A58}', '\u{1A5E}', GraphemeClusterBreak::Extend),
+    ('\u{1A60}', '\u{1A60}', GraphemeClusterBreak::Extend),
+    ('\u{1A62}', '\u{1A62}', GraphemeClusterBreak::Extend),
+    ('\u{1A65}', '\u{1A6C}', GraphemeClusterBreak::Extend),
+    ('\u{1A6D}', '\u{1A72}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1A73}', '\u{1A7C}', GraphemeClusterBreak::Extend),
+    ('\u{1A7F}', '\u{1A7F}', GraphemeClusterBreak::Extend),
+    ('\u{1AB0}', '\u{1ACE}', GraphemeClusterBreak::Extend),
+    ('\u{1B00}', '\u{1B03}', GraphemeClusterBreak::Extend),
+    ('\u{1B04}', '\u{1B04}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B34}', '\u{1B3A}', GraphemeClusterBreak::Extend),
+    ('\u{1B3B}', '\u{1B3B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B3C}', '\u{1B3C}', GraphemeClusterBreak::Extend),
+    ('\u{1B3D}', '\u{1B41}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B42}', '\u{1B42}', GraphemeClusterBreak::Extend),
+    ('\u{1B43}', '\u{1B44}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1B6B}', '\u{1B73}', GraphemeClusterBreak::Extend),
+    ('\u{1B80}', '\u{1B81}', GraphemeClusterBreak::Extend),
+    ('\u{1B82}', '\u{1B82}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BA1}', '\u{1BA1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BA2}', '\u{1BA5}', GraphemeClusterBreak::Extend),
+    ('\u{1BA6}', '\u{1BA7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BA8}', '\u{1BA9}', GraphemeClusterBreak::Extend),
+    ('\u{1BAA}', '\u{1BAA}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BAB}', '\u{1BAD}', GraphemeClusterBreak::Extend),
+    ('\u{1BE6}', '\u{1BE6}', GraphemeClusterBreak::Extend),
+    ('\u{1BE7}', '\u{1BE7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BE8}', '\u{1BE9}', GraphemeClusterBreak::Extend),
+    ('\u{1BEA}', '\u{1BEC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BED}', '\u{1BED}', GraphemeClusterBreak::Extend),
+    ('\u{1BEE}', '\u{1BEE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BEF}', '\u{1BF1}', GraphemeClusterBreak::Extend),
+    ('\u{1BF2}', '\u{1BF3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C24}', '\u{1C2B}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C2C}', '\u{1C33}', GraphemeClusterBreak::Extend),
+    ('\u{1C34}', '\u{1C35}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1C36}', '\u{1C37}', GraphemeClusterBreak::Extend),
+    ('\u{1CD0}', '\u{1CD2}', GraphemeClusterBreak::Extend),
+    ('\u{1CD4}', '\u{1CE0}', GraphemeClusterBreak::Extend),
+    ('\u{1CE1}', '\u{1CE1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1CE2}', '\u{1CE8}', GraphemeClusterBreak::Extend),
+    ('\u{1CED}', '\u{1CED}', GraphemeClusterBreak::Extend),
+    ('\u{1CF4}', '\u{1CF4}', GraphemeClusterBreak::Extend),
+    ('\u{1CF7}', '\u{1CF7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1CF8}', '\u{1CF9}', GraphemeClusterBreak::Extend),
+    ('\u{1DC0}', '\u{1DFF}', GraphemeClusterBreak::Extend),
+    ('\u{200B}', '\u{200B}', GraphemeClusterBreak::Control),
+    ('\u{200C}', '\u{200C}', GraphemeClusterBreak::Extend),
+    ('\u{200D}', '\u{200D}', GraphemeClusterBreak::ZWJ),
+    ('\u{200E}', '\u{200F}', GraphemeClusterBreak::Control),
+    ('\u{2028}', '\u{202E}', GraphemeClusterBreak::Control),
+    ('\u{2060}', '\u{206F}', GraphemeClusterBreak::Control),
+    ('\u{20D0}', '\u{20F0}', GraphemeClusterBreak::Extend),
+    ('\u{2CEF}', '\u{2CF1}', GraphemeClusterBreak::Extend),
+    ('\u{2D7F}', '\u{2D7F}', GraphemeClusterBreak::Extend),
+    ('\u{2DE0}', '\u{2DFF}', GraphemeClusterBreak::Extend),
+    ('\u{302A}', '\u{302F}', GraphemeClusterBreak::Extend),
+    ('\u{3099}', '\u{309A}', GraphemeClusterBreak::Extend),
+    ('\u{A66F}', '\u{A672}', GraphemeClusterBreak::Extend),
+    ('\u{A674}', '\u{A67D}', GraphemeClusterBreak::Extend),
+    ('\u{A69E}', '\u{A69F}', GraphemeClusterBreak::Extend),
+    ('\u{A6F0}', '\u{A6F1}', GraphemeClusterBreak::Extend),
+    ('\u{A802}', '\u{A802}', GraphemeClusterBreak::Extend),
+    ('\u{A806}', '\u{A806}', GraphemeClusterBreak::Extend),
+    ('\u{A80B}', '\u{A80B}', GraphemeClusterBreak::Extend),
+    ('\u{A823}', '\u{A824}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A825}', '\u{A826}', GraphemeClusterBreak::Extend),
+    ('\u{A827}', '\u{A827}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A82C}', '\u{A82C}', GraphemeClusterBreak::Extend),
+    ('\u{A880}', '\u{A881}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A8B4}', '\u{A8C3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A8C4}', '\u{A8C5}', GraphemeClusterBreak::Extend),
+    ('\u{A8E0}', '\u{A8F1}', GraphemeClusterBreak::Extend),
+    ('\u{A8FF}', '\u{A8FF}', GraphemeClusterBreak::Extend),
+    ('\u{A926}', '\u{A92D}', GraphemeClusterBreak::Extend),
+    ('\u{A947}', '\u{A951}', GraphemeClusterBreak::Extend),
+    ('\u{A952}', '\u{A953}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A960}', '\u{A97C}', GraphemeClusterBreak::L),
+    ('\u{A980}', '\u{A982}', GraphemeClusterBreak::Extend),
+    ('\u{A983}', '\u{A983}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9B3}', '\u{A9B3}', GraphemeClusterBreak::Extend),
+    ('\u{A9B4}', '\u{A9B5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9B6}', '\u{A9B9}', GraphemeClusterBreak::Extend),
+    ('\u{A9BA}', '\u{A9BB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9BC}', '\u{A9BD}', GraphemeClusterBreak::Extend),
+    ('\u{A9BE}', '\u{A9C0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{A9E5}', '\u{A9E5}', GraphemeClusterBreak::Extend),
+    ('\u{AA29}', '\u{AA2E}', GraphemeClusterBreak::Extend),
+    ('\u{AA2F}', '\u{AA30}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AA31}', '\u{AA32}', GraphemeClusterBreak::Extend),
+    ('\u{AA33}', '\u{AA34}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AA35}', '\u{AA36}', GraphemeClusterBreak::Extend),
+    ('\u{AA43}', '\u{AA43}', GraphemeClusterBreak::Extend),
+    ('\u{AA4C}', '\u{AA4C}', GraphemeClusterBreak::Extend),
+    ('\u{AA4D}', '\u{AA4D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AA7C}', '\u{AA7C}', GraphemeClusterBreak::Extend),
+    ('\u{AAB0}', '\u{AAB0}', GraphemeClusterBreak::Extend),
+    ('\u{AAB2}', '\u{AAB4}', GraphemeClusterBreak::Extend),
+    ('\u{AAB7}', '\u{AAB8}', GraphemeClusterBreak::Extend),
+    ('\u{AABE}', '\u{AABF}', GraphemeClusterBreak::Extend),
+    ('\u{AAC1}', '\u{AAC1}', GraphemeClusterBreak::Extend),
+    ('\u{AAEB}', '\u{AAEB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAEC}', '\u{AAED}', GraphemeClusterBreak::Extend),
+    ('\u{AAEE}', '\u{AAEF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAF5}', '\u{AAF5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{AAF6}', '\u{AAF6}', GraphemeClusterBreak::Extend),
+    ('\u{ABE3}', '\u{ABE4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABE5}', '\u{ABE5}', GraphemeClusterBreak::Extend),
+    ('\u{ABE6}', '\u{ABE7}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABE8}', '\u{ABE8}', GraphemeClusterBreak::Extend),
+    ('\u{ABE9}', '\u{ABEA}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABEC}', '\u{ABEC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{ABED}', '\u{ABED}', GraphemeClusterBreak::Extend),
+    ('\u{AC00}', '\u{AC00}', GraphemeClusterBreak::LV),
+    ('\u{AC01}', '\u{AC1B}', GraphemeClusterBreak::LVT),
+    ('\u{AC1C}', '\u{AC1C}', GraphemeClusterBreak::LV),
+    ('\u{AC1D}', '\u{AC37}', GraphemeClusterBreak::LVT),
+    ('\u{AC38}', '\u{AC38}', GraphemeClusterBreak::LV),
+    ('\u{AC39}', '\u{AC53}', GraphemeClusterBreak::LVT),
+    ('\u{AC54}', '\u{AC54}', GraphemeClusterBreak::LV),
+    ('\u{AC55}', '\u{AC6F}', GraphemeClusterBreak::LVT),
+    ('\u{AC70}', '\u{AC70}', GraphemeClusterBreak::LV),
+    ('\u{AC71}', '\u{AC8B}', GraphemeClusterBreak::LVT),
+    ('\u{AC8C}', '\u{AC8C}', GraphemeClusterBreak::LV),
+    ('\u{AC8D}', '\u{ACA7}', GraphemeClusterBreak::LVT),
+    ('\u{ACA8}', '\u{ACA8}', GraphemeClusterBreak::LV),
+    ('\u{ACA9}', '\u{ACC3}', GraphemeClusterBreak::LVT),
+    ('\u{ACC4}', '\u{ACC4}', GraphemeClusterBreak::LV),
+    ('\u{ACC5}', '\u{ACDF}', GraphemeClusterBreak::LVT),
+    ('\u{ACE0}', '\u{ACE0}', GraphemeClusterBreak::LV),
+    ('\u{ACE1}', '\u{ACFB}', GraphemeClusterBreak::LVT),
+    ('\u{ACFC}', '\u{ACFC}', GraphemeClusterBreak::LV),
+    ('\u{ACFD}', '\u{AD17}', GraphemeClusterBreak::LVT),
+    ('\u{AD18}', '\u{AD18}', GraphemeClusterBreak::LV),
+    ('\u{AD19}', '\u{AD33}', GraphemeClusterBreak::LVT),
+    ('\u{AD34}', '\u{AD34}', GraphemeClusterBreak::LV),
+    ('\u{AD35}', '\u{AD4F}', GraphemeClusterBreak::LVT),
+    ('\u{AD50}', '\u{AD50}', GraphemeClusterBreak::LV),
+    ('\u{AD51}', '\u{AD6B}', GraphemeClusterBreak::LVT),
+    ('\u{AD6C}', '\u{AD6C}', GraphemeClusterBreak::LV),
+    ('\u{AD6D}', '\u{AD87}', GraphemeClusterBreak::LVT),
+    ('\u{AD88}', '\u{AD88}', GraphemeClusterBreak::LV),
+    ('\u{AD89}', '\u{ADA3}', GraphemeClusterBreak::LVT),
+    ('\u{ADA4}', '\u{ADA4}', GraphemeClusterBreak::LV),
+    ('\u{ADA5}', '\u{ADBF}', GraphemeClusterBreak::LVT),
+    ('\u{ADC0}', '\u{ADC0}', GraphemeClusterBreak::LV),
+    ('\u{ADC1}', '\u{ADDB}', GraphemeClusterBreak::LVT),
+    ('\u{ADDC}', '\u{ADDC}', GraphemeClusterBreak::LV),
+    ('\u{ADDD}', '\u{ADF7}', GraphemeClusterBreak::LVT),
+    ('\u{ADF8}', '\u{ADF8}', GraphemeClusterBreak::LV),
+    ('\u{ADF9}', '\u{AE13}', GraphemeClusterBreak::LVT),
+    ('\u{AE14}', '\u{AE14}', GraphemeClusterBreak::LV),
+    ('\u{AE15}', '\u{AE2F}', GraphemeClusterBreak::LVT),
+    ('\u{AE30}', '\u{AE30}', GraphemeClusterBreak::LV),
+    ('\u{AE31}', '\u{AE4B}', GraphemeClusterBreak::LVT),
+    ('\u{AE4C}', '\u{AE4C}', GraphemeClusterBreak::LV),
+    ('\u{AE4D}', '\u{AE67}', GraphemeClusterBreak::LVT),
+    ('\u{AE68}', '\u{AE68}', GraphemeClusterBreak::LV),
+    ('\u{AE69}', '\u{AE83}', GraphemeClusterBreak::LVT),
+    ('\u{AE84}', '\u{AE84}', GraphemeClusterBreak::LV),
+    ('\u{AE85}', '\u{AE9F}', GraphemeClusterBreak::LVT),
+    ('\u{AEA0}', '\u{AEA0}', GraphemeClusterBreak::LV),
+    ('\u{AEA1}', '\u{AEBB}', GraphemeClusterBreak::LVT),
+    ('\u{AEBC}', '\u{AEBC}', GraphemeClusterBreak::LV),
+    ('\u{AEBD}', '\u{AED7}', GraphemeClusterBreak::LVT),
+    ('\u{AED8}', '\u{AED8}', GraphemeClusterBreak::LV),
+    ('\u{AED9}', '\u{AEF3}', GraphemeClusterBreak::LVT),
+    ('\u{AEF4}', '\u{AEF4}', GraphemeClusterBreak::LV),
+    ('\u{AEF5}', '\u{AF0F}', GraphemeClusterBreak::LVT),
+    ('\u{AF10}', '\u{AF10}', GraphemeClusterBreak::LV),
+    ('\u{AF11}', '\u{AF2B}', GraphemeClusterBreak::LVT),
+    ('\u{AF2C}', '\u{AF2C}', GraphemeClusterBreak::LV),
+    ('\u{AF2D}', '\u{AF47}', GraphemeClusterBreak::LVT),
+    ('\u{AF48}', '\u{AF48}', GraphemeClusterBreak::LV),
+    ('\u{AF49}', '\u{AF63}', GraphemeClusterBreak::LVT),
+    ('\u{AF64}', '\u{AF64}', GraphemeClusterBreak::LV),
+    ('\u{AF65}', '\u{AF7F}', GraphemeClusterBreak::LVT),
+    ('\u{AF80}', '\u{AF80}', GraphemeClusterBreak::LV),
+    ('\u{AF81}', '\u{AF9B}', GraphemeClusterBreak::LVT),
+    ('\u{AF9C}', '\u{AF9C}', GraphemeClusterBreak::LV),
+    ('\u{AF9D}', '\u{AFB7}', GraphemeClusterBreak::LVT),
+    ('\u{AFB8}', '\u{AFB8}', GraphemeClusterBreak::LV),
+    ('\u{AFB9}', '\u{AFD3}', GraphemeClusterBreak::LVT),
+    ('\u{AFD4}', '\u{AFD4}', GraphemeClusterBreak::LV),
+    ('\u{AFD5}', '\u{AFEF}', GraphemeClusterBreak::LVT),
+    ('\u{AFF0}', '\u{AFF0}', GraphemeClusterBreak::LV),
+    ('\u{AFF1}', '\u{B00B}', GraphemeClusterBreak::LVT),
+    ('\u{B00C}', '\u{B00C}', GraphemeClusterBreak::LV),
+    ('\u{B00D}', '\u{B027}', GraphemeClusterBreak::LVT),
+    ('\u{B028}', '\u{B028}', GraphemeClusterBreak::LV),
+    ('\u{B029}', '\u{B043}', GraphemeClusterBreak::LVT),
+    ('\u{B044}', '\u{B044}', GraphemeClusterBreak::LV),
+    ('\u{B045}', '\u{B05F}', GraphemeClusterBreak::LVT),
+    ('\u{B060}', '\u{B060}', GraphemeClusterBreak::LV),
+    ('\u{B061}', '\u{B07B}', GraphemeClusterBreak::LVT),
+    ('\u{B07C}', '\u{B07C}', GraphemeClusterBreak::LV),
+    ('\u{B07D}', '\u{B097}', GraphemeClusterBreak::LVT),
+    ('\u{B098}', '\u{B098}', GraphemeClusterBreak::LV),
+    ('\u{B099}', '\u{B0B3}', GraphemeClusterBreak::LVT),
+    ('\u{B0B4}', '\u{B0B4}', GraphemeClusterBreak::LV),
+    ('\u{B0B5}', '\u{B0CF}', GraphemeClusterBreak::LVT),
+    ('\u{B0D0}', '\u{B0D0}', GraphemeClusterBreak::LV),
+    ('\u{B0D1}', '\u{B0EB}', GraphemeClusterBreak::LVT),
+    ('\u{B0EC}', '\u{B0EC}', GraphemeClusterBreak::LV),
+    ('\u{B0ED}', '\u{B107}', GraphemeClusterBreak::LVT),
+    ('\u{B108}', '\u{B108}', GraphemeClusterBreak::LV),
+    ('\u{B109}', '\u{B123}', GraphemeClusterBreak::LVT),
+    ('\u{B124}', '\u{B124}', GraphemeClusterBreak::LV),
+    ('\u{B125}', '\u{B13F}', GraphemeClusterBreak::LVT),
+    ('\u{B140}', '\u{B140}', GraphemeClusterBreak::LV),
+    ('\u{B141}', '\u{B15B}', GraphemeClusterBreak::LVT),
+    ('\u{B15C}', '\u{B15C}', GraphemeClusterBreak::LV),
+    ('\u{B15D}', '\u{B177}', GraphemeClusterBreak::LVT),
+    ('\u{B178}', '\u{B178}', GraphemeClusterBreak::LV),
+    ('\u{B179}', '\u{B193}', GraphemeClusterBreak::LVT),
+    ('\u{B194}', '\u{B194}', GraphemeClusterBreak::LV),
+    ('\u{B195}', '\u{B1AF}', GraphemeClusterBreak::LVT),
+    ('\u{B1B0}', '\u{B1B0}', GraphemeClusterBreak::LV),
+    ('\u{B1B1}', '\u{B1CB}', GraphemeClusterBreak::LVT),
+    ('\u{B1CC}', '\u{B1CC}', GraphemeClusterBreak::LV),
+    ('\u{B1CD}', '\u{B1E7}', GraphemeClusterBreak::LVT),
+    ('\u{B1E8}', '\u{B1E8}', GraphemeClusterBreak::LV),
+    ('\u{B1E9}', '\u{B203}', GraphemeClusterBreak::LVT),
+    ('\u{B204}', '\u{B204}', GraphemeClusterBreak::LV),
+    ('\u{B205}', '\u{B21F}', GraphemeClusterBreak::LVT),
+    ('\u{B220}', '\u{B220}', GraphemeClusterBreak::LV),
+    ('\u{B221}', '\u{B23B}', GraphemeClusterBreak::LVT),
+    ('\u{B23C}', '\u{B23C}', GraphemeClusterBreak::LV),
+    ('\u{B23D}', '\u{B257}', GraphemeClusterBreak::LVT),
+    ('\u{B258}', '\u{B258}', GraphemeClusterBreak::LV),
+    ('\u{B259}', '\u{B273}', GraphemeClusterBreak::LVT),
+    ('\u{B274}', '\u{B274}', GraphemeClusterBreak::LV),
+    ('\u{B275}', '\u{B28F}', GraphemeClusterBreak::LVT),
+    ('\u{B290}', '\u{B290}', GraphemeClusterBreak::LV),
+    ('\u{B291}', '\u{B2AB}', GraphemeClusterBreak::LVT),
+    ('\u{B2AC}', '\u{B2AC}', GraphemeClusterBreak::LV),
+    ('\u{B2AD}', '\u{B2C7}', GraphemeClusterBreak::LVT),
+    ('\u{B2C8}', '\u{B2C8}', GraphemeClusterBreak::LV),
+    ('\u{B2C9}', '\u{B2E3}', GraphemeClusterBreak::LVT),
+    ('\u{B2E4}', '\u{B2E4}', GraphemeClusterBreak::LV),
+    ('\u{B2E5}', '\u{B2FF}', GraphemeClusterBreak::LVT),
+    ('\u{B300}', '\u{B300}', GraphemeClusterBreak::LV),
+    ('\u{B301}', '\u{B31B}', GraphemeClusterBreak::LVT),
+    ('\u{B31C}', '\u{B31C}', GraphemeClusterBreak::LV),
+    ('\u{B31D}', '\u{B337}', GraphemeClusterBreak::LVT),
+    ('\u{B338}', '\u{B338}', GraphemeClusterBreak::LV),
+    ('\u{B339}', '\u{B353}', GraphemeClusterBreak::LVT),
+    ('\u{B354}', '\u{B354}', GraphemeClusterBreak::LV),
+    ('\u{B355}', '\u{B36F}', GraphemeClusterBreak::LVT),
+    ('\u{B370}', '\u{B370}', GraphemeClusterBreak::LV),
+    ('\u{B371}', '\u{B38B}', GraphemeClusterBreak::LVT),
+    ('\u{B38C}', '\u{B38C}', GraphemeClusterBreak::LV),
+    ('\u{B38D}', '\u{B3A7}', GraphemeClusterBreak::LVT),
+    ('\u{B3A8}', '\u{B3A8}', GraphemeClusterBreak::LV),
+    ('\u{B3A9}', '\u{B3C3}', GraphemeClusterBreak::LVT),
+    ('\u{B3C4}', '\u{B3C4}', GraphemeClusterBreak::LV),
+    ('\u{B3C5}', '\u{B3DF}', GraphemeClusterBreak::LVT),
+    ('\u{B3E0}', '\u{B3E0}', GraphemeClusterBreak::LV),
+    ('\u{B3E1}', '\u{B3FB}', GraphemeClusterBreak::LVT),
+    ('\u{B3FC}', '\u{B3FC}', GraphemeClusterBreak::LV),
+    ('\u{B3FD}', '\u{B417}', GraphemeClusterBreak::LVT),
+    ('\u{B418}', '\u{B418}', GraphemeClusterBreak::LV),
+    ('\u{B419}', '\u{B433}', GraphemeClusterBreak::LVT),
+    ('\u{B434}', '\u{B434}', GraphemeClusterBreak::LV),
+    ('\u{B435}', '\u{B44F}', GraphemeClusterBreak::LVT),
+    ('\u{B450}', '\u{B450}', GraphemeClusterBreak::LV),
+    ('\u{B451}', '\u{B46B}', GraphemeClusterBreak::LVT),
+    ('\u{B46C}', '\u{B46C}', GraphemeClusterBreak::LV),
+    ('\u{B46D}', '\u{B487}', GraphemeClusterBreak::LVT),
+    ('\u{B488}', '\u{B488}', GraphemeClusterBreak::LV),
+    ('\u{B489}', '\u{B4A3}', GraphemeClusterBreak::LVT),
+    ('\u{B4A4}', '\u{B4A4}', GraphemeClusterBreak::LV),
+    ('\u{B4A5}', '\u{B4BF}', GraphemeClusterBreak::LVT),
+    ('\u{B4C0}', '\u{B4C0}', GraphemeClusterBreak::LV),
+    ('\u{B4C1}', '\u{B4DB}', GraphemeClusterBreak::LVT),
+    ('\u{B4DC}', '\u{B4DC}', GraphemeClusterBreak::LV),
+    ('\u{B4DD}', '\u{B4F7}', GraphemeClusterBreak::LVT),
+    ('\u{B4F8}', '\u{B4F8}', GraphemeClusterBreak::LV),
+    ('\u{B4F9}', '\u{B513}', GraphemeClusterBreak::LVT),
+    ('\u{B514}', '\u{B514}', GraphemeClusterBreak::LV),
+    ('\u{B515}', '\u{B52F}', GraphemeClusterBreak::LVT),
+    ('\u{B530}', '\u{B530}', GraphemeClusterBreak::LV),
+    ('\u{B531}', '\u{B54B}', GraphemeClusterBreak::LVT),
+    ('\u{B54C}', '\u{B54C}', GraphemeClusterBreak::LV),
+    ('\u{B54D}', '\u{B567}', GraphemeClusterBreak::LVT),
+    ('\u{B568}', '\u{B568}', GraphemeClusterBreak::LV),
+    ('\u{B569}', '\u{B583}', GraphemeClusterBreak::LVT),
+    ('\u{B584}', '\u{B584}', GraphemeClusterBreak::LV),
+    ('\u{B585}', '\u{B59F}', GraphemeClusterBreak::LVT),
+    ('\u{B5A0}', '\u{B5A0}', GraphemeClusterBreak::LV),
+    ('\u{B5A1}', '\u{B5BB}', GraphemeClusterBreak::LVT),
+    ('\u{B5BC}', '\u{B5BC}', GraphemeClusterBreak::LV),
+    ('\u{B5BD}', '\u{B5D7}', GraphemeClusterBreak::LVT),
+    ('\u{B5D8}', '\u{B5D8}', GraphemeClusterBreak::LV),
+    ('\u{B5D9}', '\u{B5F3}', GraphemeClusterBreak::LVT),
+    ('\u{B5F4}', '\u{B5F4}', GraphemeClusterBreak::LV),
+    ('\u{B5F5}', '\u{B60F}', GraphemeClusterBreak::LVT),
+    ('\u{B610}', '\u{B610}', GraphemeClusterBreak::LV),
+    ('\u{B611}', '\u{B62B}', GraphemeClusterBreak::LVT),
+    ('\u{B62C}', '\u{B62C}', GraphemeClusterBreak::LV),
+    ('\u{B62D}', '\u{B647}', GraphemeClusterBreak::LVT),
+    ('\u{B648}', '\u{B648}', GraphemeClusterBreak::LV),
+    ('\u{B649}', '\u{B663}', GraphemeClusterBreak::LVT),
+    ('\u{B664}', '\u{B664}', GraphemeClusterBreak::LV),
+    ('\u{B665}', '\u{B67F}', GraphemeClusterBreak::LVT),
+    ('\u{B680}', '\u{B680}', GraphemeClusterBreak::LV),
+    ('\u{B681}', '\u{B69B}', GraphemeClusterBreak::LVT),
+    ('\u{B69C}', '\u{B69C}', GraphemeClusterBreak::LV),
+    ('\u{B69D}', '\u{B6B7}', GraphemeClusterBreak::LVT),
+    ('\u{B6B8}', '\u{B6B8}', GraphemeClusterBreak::LV),
+    ('\u{B6B9}', '\u{B6D3}', GraphemeClusterBreak::LVT),
+    ('\u{B6D4}', '\u{B6D4}', GraphemeClusterBreak::LV),
+    ('\u{B6D5}', '\u{B6EF}', GraphemeClusterBreak::LVT),
+    ('\u{B6F0}', '\u{B6F0}', GraphemeClusterBreak::LV),
+    ('\u{B6F1}', '\u{B70B}', GraphemeClusterBreak::LVT),
+    ('\u{B70C}', '\u{B70C}', GraphemeClusterBreak::LV),
+    ('\u{B70D}', '\u{B727}', GraphemeClusterBreak::LVT),
+    ('\u{B728}', '\u{B728}', GraphemeClusterBreak::LV),
+    ('\u{B729}', '\u{B743}', GraphemeClusterBreak::LVT),
+    ('\u{B744}', '\u{B744}', GraphemeClusterBreak::LV),
+    ('\u{B745}', '\u{B75F}', GraphemeClusterBreak::LVT),
+    ('\u{B760}', '\u{B760}', GraphemeClusterBreak::LV),
+    ('\u{B761}', '\u{B77B}', GraphemeClusterBreak::LVT),
+    ('\u{B77C}', '\u{B77C}', GraphemeClusterBreak::LV),
+    ('\u{B77D}', '\u{B797}', GraphemeClusterBreak::LVT),
+    ('\u{B798}', '\u{B798}', GraphemeClusterBreak::LV),
+    ('\u{B799}', '\u{B7B3}', GraphemeClusterBreak::LVT),
+    ('\u{B7B4}', '\u{B7B4}', GraphemeClusterBreak::LV),
+    ('\u{B7B5}', '\u{B7CF}', GraphemeClusterBreak::LVT),
+    ('\u{B7D0}', '\u{B7D0}', GraphemeClusterBreak::LV),
+    ('\u{B7D1}', '\u{B7EB}', GraphemeClusterBreak::LVT),
+    ('\u{B7EC}', '\u{B7EC}', GraphemeClusterBreak::LV),
+    ('\u{B7ED}', '\u{B807}', GraphemeClusterBreak::LVT),
+    ('\u{B808}', '\u{B808}', GraphemeClusterBreak::LV),
+    ('\u{B809}', '\u{B823}', GraphemeClusterBreak::LVT),
+    ('\u{B824}', '\u{B824}', GraphemeClusterBreak::LV),
+    ('\u{B825}', '\u{B83F}', GraphemeClusterBreak::LVT),
+    ('\u{B840}', '\u{B840}', GraphemeClusterBreak::LV),
+    ('\u{B841}', '\u{B85B}', GraphemeClusterBreak::LVT),
+    ('\u{B85C}', '\u{B85C}', GraphemeClusterBreak::LV),
+    ('\u{B85D}', '\u{B877}', GraphemeClusterBreak::LVT),
+    ('\u{B878}', '\u{B878}', GraphemeClusterBreak::LV),
+    ('\u{B879}', '\u{B893}', GraphemeClusterBreak::LVT),
+    ('\u{B894}', '\u{B894}', GraphemeClusterBreak::LV),
+    ('\u{B895}', '\u{B8AF}', GraphemeClusterBreak::LVT),
+    ('\u{B8B0}', '\u{B8B0}', GraphemeClusterBreak::LV),
+    ('\u{B8B1}', '\u{B8CB}', GraphemeClusterBreak::LVT),
+    ('\u{B8CC}', '\u{B8CC}', GraphemeClusterBreak::LV),
+    ('\u{B8CD}', '\u{B8E7}', GraphemeClusterBreak::LVT),
+    ('\u{B8E8}', '\u{B8E8}', GraphemeClusterBreak::LV),
+    ('\u{B8E9}', '\u{B903}', GraphemeClusterBreak::LVT),
+    ('\u{B904}', '\u{B904}', GraphemeClusterBreak::LV),
+    ('\u{B905}', '\u{B91F}', GraphemeClusterBreak::LVT),
+    ('\u{B920}', '\u{B920}', GraphemeClusterBreak::LV),
+    ('\u{B921}', '\u{B93B}', GraphemeClusterBreak::LVT),
+    ('\u{B93C}', '\u{B93C}', GraphemeClusterBreak::LV),
+    ('\u{B93D}', '\u{B957}', GraphemeClusterBreak::LVT),
+    ('\u{B958}', '\u{B958}', GraphemeClusterBreak::LV),
+    ('\u{B959}', '\u{B973}', GraphemeClusterBreak::LVT),
+    ('\u{B974}', '\u{B974}', GraphemeClusterBreak::LV),
+    ('\u{B975}', '\u{B98F}', GraphemeClusterBreak::LVT),
+    ('\u{B990}', '\u{B990}', GraphemeClusterBreak::LV),
+    ('\u{B991}', '\u{B9AB}', GraphemeClusterBreak::LVT),
+    ('\u{B9AC}', '\u{B9AC}', GraphemeClusterBreak::LV),
+    ('\u{B9AD}', '\u{B9C7}', GraphemeClusterBreak::LVT),
+    ('\u{B9C8}', '\u{B9C8}', GraphemeClusterBreak::LV),
+    ('\u{B9C9}', '\u{B9E3}', GraphemeClusterBreak::LVT),
+    ('\u{B9E4}', '\u{B9E4}', GraphemeClusterBreak::LV),
+    ('\u{B9E5}', '\u{B9FF}', GraphemeClusterBreak::LVT),
+    ('\u{BA00}', '\u{BA00}', GraphemeClusterBreak::LV),
+    ('\u{BA01}', '\u{BA1B}', GraphemeClusterBreak::LVT),
+    ('\u{BA1C}', '\u{BA1C}', GraphemeClusterBreak::LV),
+    ('\u{BA1D}', '\u{BA37}', GraphemeClusterBreak::LVT),
+    ('\u{BA38}', '\u{BA38}', GraphemeClusterBreak::LV),
+    ('\u{BA39}', '\u{BA53}', GraphemeClusterBreak::LVT),
+    ('\u{BA54}', '\u{BA54}', GraphemeClusterBreak::LV),
+    ('\u{BA55}', '\u{BA6F}', GraphemeClusterBreak::LVT),
+    ('\u{BA70}', '\u{BA70}', GraphemeClusterBreak::LV),
+    ('\u{BA71}', '\u{BA8B}', GraphemeClusterBreak::LVT),
+    ('\u{BA8C}', '\u{BA8C}', GraphemeClusterBreak::LV),
+    ('\u{BA8D}', '\u{BAA7}', GraphemeClusterBreak::LVT),
+    ('\u{BAA8}', '\u{BAA8}', GraphemeClusterBreak::LV),
+    ('\u{BAA9}', '\u{BAC3}', GraphemeClusterBreak::LVT),
+    ('\u{BAC4}', '\u{BAC4}', GraphemeClusterBreak::LV),
+    ('\u{BAC5}', '\u{BADF}', GraphemeClusterBreak::LVT),
+    ('\u{BAE0}', '\u{BAE0}', GraphemeClusterBreak::LV),
+    ('\u{BAE1}', '\u{BAFB}', GraphemeClusterBreak::LVT),
+    ('\u{BAFC}', '\u{BAFC}', GraphemeClusterBreak::LV),
+    ('\u{BAFD}', '\u{BB17}', GraphemeClusterBreak::LVT),
+    ('\u{BB18}', '\u{BB18}', GraphemeClusterBreak::LV),
+    ('\u{BB19}', '\u{BB33}', GraphemeClusterBreak::LVT),
+    ('\u{BB34}', '\u{BB34}', GraphemeClusterBreak::LV),
+    ('\u{BB35}', '\u{BB4F}', GraphemeClusterBreak::LVT),
+    ('\u{BB50}', '\u{BB50}', GraphemeClusterBreak::LV),
+    ('\u{BB51}', '\u{BB6B}', GraphemeClusterBreak::LVT),
+    ('\u{BB6C}', '\u{BB6C}', GraphemeClusterBreak::LV),
+    ('\u{BB6D}', '\u{BB87}', GraphemeClusterBreak::LVT),
+    ('\u{BB88}', '\u{BB88}', GraphemeClusterBreak::LV),
+    ('\u{BB89}', '\u{BBA3}', GraphemeClusterBreak::LVT),
+    ('\u{BBA4}', '\u{BBA4}', GraphemeClusterBreak::LV),
+    ('\u{BBA5}', '\u{BBBF}', GraphemeClusterBreak::LVT),
+    ('\u{BBC0}', '\u{BBC0}', GraphemeClusterBreak::LV),
+    ('\u{BBC1}', '\u{BBDB}', GraphemeClusterBreak::LVT),
+    ('\u{BBDC}', '\u{BBDC}', GraphemeClusterBreak::LV),
+    ('\u{BBDD}', '\u{BBF7}', GraphemeClusterBreak::LVT),
+    ('\u{BBF8}', '\u{BBF8}', GraphemeClusterBreak::LV),
+    ('\u{BBF9}', '\u{BC13}', GraphemeClusterBreak::LVT),
+    ('\u{BC14}', '\u{BC14}', GraphemeClusterBreak::LV),
+    ('\u{BC15}', '\u{BC2F}', GraphemeClusterBreak::LVT),
+    ('\u{BC30}', '\u{BC30}', GraphemeClusterBreak::LV),
+    ('\u{BC31}', '\u{BC4B}', GraphemeClusterBreak::LVT),
+    ('\u{BC4C}', '\u{BC4C}', GraphemeClusterBreak::LV),
+    ('\u{BC4D}', '\u{BC67}', GraphemeClusterBreak::LVT),
+    ('\u{BC68}', '\u{BC68}', GraphemeClusterBreak::LV),
+    ('\u{BC69}', '\u{BC83}', GraphemeClusterBreak::LVT),
+    ('\u{BC84}', '\u{BC84}', GraphemeClusterBreak::LV),
+    ('\u{BC85}', '\u{BC9F}', GraphemeClusterBreak::LVT),
+    ('\u{BCA0}', '\u{BCA0}', GraphemeClusterBreak::LV),
+    ('\u{BCA1}', '\u{BCBB}', GraphemeClusterBreak::LVT),
+    ('\u{BCBC}', '\u{BCBC}', GraphemeClusterBreak::LV),
+    ('\u{BCBD}', '\u{BCD7}', GraphemeClusterBreak::LVT),
+    ('\u{BCD8}', '\u{BCD8}', GraphemeClusterBreak::LV),
+    ('\u{BCD9}', '\u{BCF3}', GraphemeClusterBreak::LVT),
+    ('\u{BCF4}', '\u{BCF4}', GraphemeClusterBreak::LV),
+    ('\u{BCF5}', '\u{BD0F}', GraphemeClusterBreak::LVT),
+    ('\u{BD10}', '\u{BD10}', GraphemeClusterBreak::LV),
+    ('\u{BD11}', '\u{BD2B}', GraphemeClusterBreak::LVT),
+    ('\u{BD2C}', '\u{BD2C}', GraphemeClusterBreak::LV),
+    ('\u{BD2D}', '\u{BD47}', GraphemeClusterBreak::LVT),
+    ('\u{BD48}', '\u{BD48}', GraphemeClusterBreak::LV),
+    ('\u{BD49}', '\u{BD63}', GraphemeClusterBreak::LVT),
+    ('\u{BD64}', '\u{BD64}', GraphemeClusterBreak::LV),
+    ('\u{BD65}', '\u{BD7F}', GraphemeClusterBreak::LVT),
+    ('\u{BD80}', '\u{BD80}', GraphemeClusterBreak::LV),
+    ('\u{BD81}', '\u{BD9B}', GraphemeClusterBreak::LVT),
+    ('\u{BD9C}', '\u{BD9C}', GraphemeClusterBreak::LV),
+    ('\u{BD9D}', '\u{BDB7}', GraphemeClusterBreak::LVT),
+    ('\u{BDB8}', '\u{BDB8}', GraphemeClusterBreak::LV),
+    ('\u{BDB9}', '\u{BDD3}', GraphemeClusterBreak::LVT),
+    ('\u{BDD4}', '\u{BDD4}', GraphemeClusterBreak::LV),
+    ('\u{BDD5}', '\u{BDEF}', GraphemeClusterBreak::LVT),
+    ('\u{BDF0}', '\u{BDF0}', GraphemeClusterBreak::LV),
+    ('\u{BDF1}', '\u{BE0B}', GraphemeClusterBreak::LVT),
+    ('\u{BE0C}', '\u{BE0C}', GraphemeClusterBreak::LV),
+    ('\u{BE0D}', '\u{BE27}', GraphemeClusterBreak::LVT),
+    ('\u{BE28}', '\u{BE28}', GraphemeClusterBreak::LV),
+    ('\u{BE29}', '\u{BE43}', GraphemeClusterBreak::LVT),
+    ('\u{BE44}', '\u{BE44}', GraphemeClusterBreak::LV),
+    ('\u{BE45}', '\u{BE5F}', GraphemeClusterBreak::LVT),
+    ('\u{BE60}', '\u{BE60}', GraphemeClusterBreak::LV),
+    ('\u{BE61}', '\u{BE7B}', GraphemeClusterBreak::LVT),
+    ('\u{BE7C}', '\u{BE7C}', GraphemeClusterBreak::LV),
+    ('\u{BE7D}', '\u{BE97}', GraphemeClusterBreak::LVT),
+    ('\u{BE98}', '\u{BE98}', GraphemeClusterBreak::LV),
+    ('\u{BE99}', '\u{BEB3}', GraphemeClusterBreak::LVT),
+    ('\u{BEB4}', '\u{BEB4}', GraphemeClusterBreak::LV),
+    ('\u{BEB5}', '\u{BECF}', GraphemeClusterBreak::LVT),
+    ('\u{BED0}', '\u{BED0}', GraphemeClusterBreak::LV),
+    ('\u{BED1}', '\u{BEEB}', GraphemeClusterBreak::LVT),
+    ('\u{BEEC}', '\u{BEEC}', GraphemeClusterBreak::LV),
+    ('\u{BEED}', '\u{BF07}', GraphemeClusterBreak::LVT),
+    ('\u{BF08}', '\u{BF08}', GraphemeClusterBreak::LV),
+    ('\u{BF09}', '\u{BF23}', GraphemeClusterBreak::LVT),
+    ('\u{BF24}', '\u{BF24}', GraphemeClusterBreak::LV),
+    ('\u{BF25}', '\u{BF3F}', GraphemeClusterBreak::LVT),
+    ('\u{BF40}', '\u{BF40}', GraphemeClusterBreak::LV),
+    ('\u{BF41}', '\u{BF5B}', GraphemeClusterBreak::LVT),
+    ('\u{BF5C}', '\u{BF5C}', GraphemeClusterBreak::LV),
+    ('\u{BF5D}', '\u{BF77}', GraphemeClusterBreak::LVT),
+    ('\u{BF78}', '\u{BF78}', GraphemeClusterBreak::LV),
+    ('\u{BF79}', '\u{BF93}', GraphemeClusterBreak::LVT),
+    ('\u{BF94}', '\u{BF94}', GraphemeClusterBreak::LV),
+    ('\u{BF95}', '\u{BFAF}', GraphemeClusterBreak::LVT),
+    ('\u{BFB0}', '\u{BFB0}', GraphemeClusterBreak::LV),
+    ('\u{BFB1}', '\u{BFCB}', GraphemeClusterBreak::LVT),
+    ('\u{BFCC}', '\u{BFCC}', GraphemeClusterBreak::LV),
+    ('\u{BFCD}', '\u{BFE7}', GraphemeClusterBreak::LVT),
+    ('\u{BFE8}', '\u{BFE8}', GraphemeClusterBreak::LV),
+    ('\u{BFE9}', '\u{C003}', GraphemeClusterBreak::LVT),
+    ('\u{C004}', '\u{C004}', GraphemeClusterBreak::LV),
+    ('\u{C005}', '\u{C01F}', GraphemeClusterBreak::LVT),
+    ('\u{C020}', '\u{C020}', GraphemeClusterBreak::LV),
+    ('\u{C021}', '\u{C03B}', GraphemeClusterBreak::LVT),
+    ('\u{C03C}', '\u{C03C}', GraphemeClusterBreak::LV),
+    ('\u{C03D}', '\u{C057}', GraphemeClusterBreak::LVT),
+    ('\u{C058}', '\u{C058}', GraphemeClusterBreak::LV),
+    ('\u{C059}', '\u{C073}', GraphemeClusterBreak::LVT),
+    ('\u{C074}', '\u{C074}', GraphemeClusterBreak::LV),
+    ('\u{C075}', '\u{C08F}', GraphemeClusterBreak::LVT),
+    ('\u{C090}', '\u{C090}', GraphemeClusterBreak::LV),
+    ('\u{C091}', '\u{C0AB}', GraphemeClusterBreak::LVT),
+    ('\u{C0AC}', '\u{C0AC}', GraphemeClusterBreak::LV),
+    ('\u{C0AD}', '\u{C0C7}', GraphemeClusterBreak::LVT),
+    ('\u{C0C8}', '\u{C0C8}', GraphemeClusterBreak::LV),
+    ('\u{C0C9}', '\u{C0E3}', GraphemeClusterBreak::LVT),
+    ('\u{C0E4}', '\u{C0E4}', GraphemeClusterBreak::LV),
+    ('\u{C0E5}', '\u{C0FF}', GraphemeClusterBreak::LVT),
+    ('\u{C100}', '\u{C100}', GraphemeClusterBreak::LV),
+    ('\u{C101}', '\u{C11B}', GraphemeClusterBreak::LVT),
+    ('\u{C11C}', '\u{C11C}', GraphemeClusterBreak::LV),
+    ('\u{C11D}', '\u{C137}', GraphemeClusterBreak::LVT),
+    ('\u{C138}', '\u{C138}', GraphemeClusterBreak::LV),
+    ('\u{C139}', '\u{C153}', GraphemeClusterBreak::LVT),
+    ('\u{C154}', '\u{C154}', GraphemeClusterBreak::LV),
+    ('\u{C155}', '\u{C16F}', GraphemeClusterBreak::LVT),
+    ('\u{C170}', '\u{C170}', GraphemeClusterBreak::LV),
+    ('\u{C171}', '\u{C18B}', GraphemeClusterBreak::LVT),
+    ('\u{C18C}', '\u{C18C}', GraphemeClusterBreak::LV),
+    ('\u{C18D}', '\u{C1A7}', GraphemeClusterBreak::LVT),
+    ('\u{C1A8}', '\u{C1A8}', GraphemeClusterBreak::LV),
+    ('\u{C1A9}', '\u{C1C3}', GraphemeClusterBreak::LVT),
+    ('\u{C1C4}', '\u{C1C4}', GraphemeClusterBreak::LV),
+    ('\u{C1C5}', '\u{C1DF}', GraphemeClusterBreak::LVT),
+    ('\u{C1E0}', '\u{C1E0}', GraphemeClusterBreak::LV),
+    ('\u{C1E1}', '\u{C1FB}', GraphemeClusterBreak::LVT),
+    ('\u{C1FC}', '\u{C1FC}', GraphemeClusterBreak::LV),
+    ('\u{C1FD}', '\u{C217}', GraphemeClusterBreak::LVT),
+    ('\u{C218}', '\u{C218}', GraphemeClusterBreak::LV),
+    ('\u{C219}', '\u{C233}', GraphemeClusterBreak::LVT),
+    ('\u{C234}', '\u{C234}', GraphemeClusterBreak::LV),
+    ('\u{C235}', '\u{C24F}', GraphemeClusterBreak::LVT),
+    ('\u{C250}', '\u{C250}', GraphemeClusterBreak::LV),
+    ('\u{C251}', '\u{C26B}', GraphemeClusterBreak::LVT),
+    ('\u{C26C}', '\u{C26C}', GraphemeClusterBreak::LV),
+    ('\u{C26D}', '\u{C287}', GraphemeClusterBreak::LVT),
+    ('\u{C288}', '\u{C288}', GraphemeClusterBreak::LV),
+    ('\u{C289}', '\u{C2A3}', GraphemeClusterBreak::LVT),
+    ('\u{C2A4}', '\u{C2A4}', GraphemeClusterBreak::LV),
+    ('\u{C2A5}', '\u{C2BF}', GraphemeClusterBreak::LVT),
+    ('\u{C2C0}', '\u{C2C0}', GraphemeClusterBreak::LV),
+    ('\u{C2C1}', '\u{C2DB}', GraphemeClusterBreak::LVT),
+    ('\u{C2DC}', '\u{C2DC}', GraphemeClusterBreak::LV),
+    ('\u{C2DD}', '\u{C2F7}', GraphemeClusterBreak::LVT),
+    ('\u{C2F8}', '\u{C2F8}', GraphemeClusterBreak::LV),
+    ('\u{C2F9}', '\u{C313}', GraphemeClusterBreak::LVT),
+    ('\u{C314}', '\u{C314}', GraphemeClusterBreak::LV),
+    ('\u{C315}', '\u{C32F}', GraphemeClusterBreak::LVT),
+    ('\u{C330}', '\u{C330}', GraphemeClusterBreak::LV),
+    ('\u{C331}', '\u{C34B}', GraphemeClusterBreak::LVT),
+    ('\u{C34C}', '\u{C34C}', GraphemeClusterBreak::LV),
+    ('\u{C34D}', '\u{C367}', GraphemeClusterBreak::LVT),
+    ('\u{C368}', '\u{C368}', GraphemeClusterBreak::LV),
+    ('\u{C369}', '\u{C383}', GraphemeClusterBreak::LVT),
+    ('\u{C384}', '\u{C384}', GraphemeClusterBreak::LV),
+    ('\u{C385}', '\u{C39F}', GraphemeClusterBreak::LVT),
+    ('\u{C3A0}', '\u{C3A0}', GraphemeClusterBreak::LV),
+    ('\u{C3A1}', '\u{C3BB}', GraphemeClusterBreak::LVT),
+    ('\u{C3BC}', '\u{C3BC}', GraphemeClusterBreak::LV),
+    ('\u{C3BD}', '\u{C3D7}', GraphemeClusterBreak::LVT),
+    ('\u{C3D8}', '\u{C3D8}', GraphemeClusterBreak::LV),
+    ('\u{C3D9}', '\u{C3F3}', GraphemeClusterBreak::LVT),
+    ('\u{C3F4}', '\u{C3F4}', GraphemeClusterBreak::LV),
+    ('\u{C3F5}', '\u{C40F}', GraphemeClusterBreak::LVT),
+    ('\u{C410}', '\u{C410}', GraphemeClusterBreak::LV),
+    ('\u{C411}', '\u{C42B}', GraphemeClusterBreak::LVT),
+    ('\u{C42C}', '\u{C42C}', GraphemeClusterBreak::LV),
+    ('\u{C42D}', '\u{C447}', GraphemeClusterBreak::LVT),
+    ('\u{C448}', '\u{C448}', GraphemeClusterBreak::LV),
+    ('\u{C449}', '\u{C463}', GraphemeClusterBreak::LVT),
+    ('\u{C464}', '\u{C464}', GraphemeClusterBreak::LV),
+    ('\u{C465}', '\u{C47F}', GraphemeClusterBreak::LVT),
+    ('\u{C480}', '\u{C480}', GraphemeClusterBreak::LV),
+    ('\u{C481}', '\u{C49B}', GraphemeClusterBreak::LVT),
+    ('\u{C49C}', '\u{C49C}', GraphemeClusterBreak::LV),
+    ('\u{C49D}', '\u{C4B7}', GraphemeClusterBreak::LVT),
+    ('\u{C4B8}', '\u{C4B8}', GraphemeClusterBreak::LV),
+    ('\u{C4B9}', '\u{C4D3}', GraphemeClusterBreak::LVT),
+    ('\u{C4D4}', '\u{C4D4}', GraphemeClusterBreak::LV),
+    ('\u{C4D5}', '\u{C4EF}', GraphemeClusterBreak::LVT),
+    ('\u{C4F0}', '\u{C4F0}', GraphemeClusterBreak::LV),
+    ('\u{C4F1}', '\u{C50B}', GraphemeClusterBreak::LVT),
+    ('\u{C50C}', '\u{C50C}', GraphemeClusterBreak::LV),
+    ('\u{C50D}', '\u{C527}', GraphemeClusterBreak::LVT),
+    ('\u{C528}', '\u{C528}', GraphemeClusterBreak::LV),
+    ('\u{C529}', '\u{C543}', GraphemeClusterBreak::LVT),
+    ('\u{C544}', '\u{C544}', GraphemeClusterBreak::LV),
+    ('\u{C545}', '\u{C55F}', GraphemeClusterBreak::LVT),
+    ('\u{C560}', '\u{C560}', GraphemeClusterBreak::LV),
+    ('\u{C561}', '\u{C57B}', GraphemeClusterBreak::LVT),
+    ('\u{C57C}', '\u{C57C}', GraphemeClusterBreak::LV),
+    ('\u{C57D}', '\u{C597}', GraphemeClusterBreak::LVT),
+    ('\u{C598}', '\u{C598}', GraphemeClusterBreak::LV),
+    ('\u{C599}', '\u{C5B3}', GraphemeClusterBreak::LVT),
+    ('\u{C5B4}', '\u{C5B4}', GraphemeClusterBreak::LV),
+    ('\u{C5B5}', '\u{C5CF}', GraphemeClusterBreak::LVT),
+    ('\u{C5D0}', '\u{C5D0}', GraphemeClusterBreak::LV),
+    ('\u{C5D1}', '\u{C5EB}', GraphemeClusterBreak::LVT),
+    ('\u{C5EC}', '\u{C5EC}', GraphemeClusterBreak::LV),
+    ('\u{C5ED}', '\u{C607}', GraphemeClusterBreak::LVT),
+    ('\u{C608}', '\u{C608}', GraphemeClusterBreak::LV),
+    ('\u{C609}', '\u{C623}', GraphemeClusterBreak::LVT),
+    ('\u{C624}', '\u{C624}', GraphemeClusterBreak::LV),
+    ('\u{C625}', '\u{C63F}', GraphemeClusterBreak::LVT),
+    ('\u{C640}', '\u{C640}', GraphemeClusterBreak::LV),
+    ('\u{C641}', '\u{C65B}', GraphemeClusterBreak::LVT),
+    ('\u{C65C}', '\u{C65C}', GraphemeClusterBreak::LV),
+    ('\u{C65D}', '\u{C677}', GraphemeClusterBreak::LVT),
+    ('\u{C678}', '\u{C678}', GraphemeClusterBreak::LV),
+    ('\u{C679}', '\u{C693}', GraphemeClusterBreak::LVT),
+    ('\u{C694}', '\u{C694}', GraphemeClusterBreak::LV),
+    ('\u{C695}', '\u{C6AF}', GraphemeClusterBreak::LVT),
+    ('\u{C6B0}', '\u{C6B0}', GraphemeClusterBreak::LV),
+    ('\u{C6B1}', '\u{C6CB}', GraphemeClusterBreak::LVT),
+    ('\u{C6CC}', '\u{C6CC}', GraphemeClusterBreak::LV),
+    ('\u{C6CD}', '\u{C6E7}', GraphemeClusterBreak::LVT),
+    ('\u{C6E8}', '\u{C6E8}', GraphemeClusterBreak::LV),
+    ('\u{C6E9}', '\u{C703}', GraphemeClusterBreak::LVT),
+    ('\u{C704}', '\u{C704}', GraphemeClusterBreak::LV),
+    ('\u{C705}', '\u{C71F}', GraphemeClusterBreak::LVT),
+    ('\u{C720}', '\u{C720}', GraphemeClusterBreak::LV),
+    ('\u{C721}', '\u{C73B}', GraphemeClusterBreak::LVT),
+    ('\u{C73C}', '\u{C73C}', GraphemeClusterBreak::LV),
+    ('\u{C73D}', '\u{C757}', GraphemeClusterBreak::LVT),
+    ('\u{C758}', '\u{C758}', GraphemeClusterBreak::LV),
+    ('\u{C759}', '\u{C773}', GraphemeClusterBreak::LVT),
+    ('\u{C774}', '\u{C774}', GraphemeClusterBreak::LV),
+    ('\u{C775}', '\u{C78F}', GraphemeClusterBreak::LVT),
+    ('\u{C790}', '\u{C790}', GraphemeClusterBreak::LV),
+    ('\u{C791}', '\u{C7AB}', GraphemeClusterBreak::LVT),
+    ('\u{C7AC}', '\u{C7AC}', GraphemeClusterBreak::LV),
+    ('\u{C7AD}', '\u{C7C7}', GraphemeClusterBreak::LVT),
+    ('\u{C7C8}', '\u{C7C8}', GraphemeClusterBreak::LV),
+    ('\u{C7C9}', '\u{C7E3}', GraphemeClusterBreak::LVT),
+    ('\u{C7E4}', '\u{C7E4}', GraphemeClusterBreak::LV),
+    ('\u{C7E5}', '\u{C7FF}', GraphemeClusterBreak::LVT),
+    ('\u{C800}', '\u{C800}', GraphemeClusterBreak::LV),
+    ('\u{C801}', '\u{C81B}', GraphemeClusterBreak::LVT),
+    ('\u{C81C}', '\u{C81C}', GraphemeClusterBreak::LV),
+    ('\u{C81D}', '\u{C837}', GraphemeClusterBreak::LVT),
+    ('\u{C838}', '\u{C838}', GraphemeClusterBreak::LV),
+    ('\u{C839}', '\u{C853}', GraphemeClusterBreak::LVT),
+    ('\u{C854}', '\u{C854}', GraphemeClusterBreak::LV),
+    ('\u{C855}', '\u{C86F}', GraphemeClusterBreak::LVT),
+    ('\u{C870}', '\u{C870}', GraphemeClusterBreak::LV),
+    ('\u{C871}', '\u{C88B}', GraphemeClusterBreak::LVT),
+    ('\u{C88C}', '\u{C88C}', GraphemeClusterBreak::LV),
+    ('\u{C88D}', '\u{C8A7}', GraphemeClusterBreak::LVT),
+    ('\u{C8A8}', '\u{C8A8}', GraphemeClusterBreak::LV),
+    ('\u{C8A9}', '\u{C8C3}', GraphemeClusterBreak::LVT),
+    ('\u{C8C4}', '\u{C8C4}', GraphemeClusterBreak::LV),
+    ('\u{C8C5}', '\u{C8DF}', GraphemeClusterBreak::LVT),
+    ('\u{C8E0}', '\u{C8E0}', GraphemeClusterBreak::LV),
+    ('\u{C8E1}', '\u{C8FB}', GraphemeClusterBreak::LVT),
+    ('\u{C8FC}', '\u{C8FC}', GraphemeClusterBreak::LV),
+    ('\u{C8FD}', '\u{C917}', GraphemeClusterBreak::LVT),
+    ('\u{C918}', '\u{C918}', GraphemeClusterBreak::LV),
+    ('\u{C919}', '\u{C933}', GraphemeClusterBreak::LVT),
+    ('\u{C934}', '\u{C934}', GraphemeClusterBreak::LV),
+    ('\u{C935}', '\u{C94F}', GraphemeClusterBreak::LVT),
+    ('\u{C950}', '\u{C950}', GraphemeClusterBreak::LV),
+    ('\u{C951}', '\u{C96B}', GraphemeClusterBreak::LVT),
+    ('\u{C96C}', '\u{C96C}', GraphemeClusterBreak::LV),
+    ('\u{C96D}', '\u{C987}', GraphemeClusterBreak::LVT),
+    ('\u{C988}', '\u{C988}', GraphemeClusterBreak::LV),
+    ('\u{C989}', '\u{C9A3}', GraphemeClusterBreak::LVT),
+    ('\u{C9A4}', '\u{C9A4}', GraphemeClusterBreak::LV),
+    ('\u{C9A5}', '\u{C9BF}', GraphemeClusterBreak::LVT),
+    ('\u{C9C0}', '\u{C9C0}', GraphemeClusterBreak::LV),
+    ('\u{C9C1}', '\u{C9DB}', GraphemeClusterBreak::LVT),
+    ('\u{C9DC}', '\u{C9DC}', GraphemeClusterBreak::LV),
+    ('\u{C9DD}', '\u{C9F7}', GraphemeClusterBreak::LVT),
+    ('\u{C9F8}', '\u{C9F8}', GraphemeClusterBreak::LV),
+    ('\u{C9F9}', '\u{CA13}', GraphemeClusterBreak::LVT),
+    ('\u{CA14}', '\u{CA14}', GraphemeClusterBreak::LV),
+    ('\u{CA15}', '\u{CA2F}', GraphemeClusterBreak::LVT),
+    ('\u{CA30}', '\u{CA30}', GraphemeClusterBreak::LV),
+    ('\u{CA31}', '\u{CA4B}', GraphemeClusterBreak::LVT),
+    ('\u{CA4C}', '\u{CA4C}', GraphemeClusterBreak::LV),
+    ('\u{CA4D}', '\u{CA67}', GraphemeClusterBreak::LVT),
+    ('\u{CA68}', '\u{CA68}', GraphemeClusterBreak::LV),
+    ('\u{CA69}', '\u{CA83}', GraphemeClusterBreak::LVT),
+    ('\u{CA84}', '\u{CA84}', GraphemeClusterBreak::LV),
+    ('\u{CA85}', '\u{CA9F}', GraphemeClusterBreak::LVT),
+    ('\u{CAA0}', '\u{CAA0}', GraphemeClusterBreak::LV),
+    ('\u{CAA1}', '\u{CABB}', GraphemeClusterBreak::LVT),
+    ('\u{CABC}', '\u{CABC}', GraphemeClusterBreak::LV),
+    ('\u{CABD}', '\u{CAD7}', GraphemeClusterBreak::LVT),
+    ('\u{CAD8}', '\u{CAD8}', GraphemeClusterBreak::LV),
+    ('\u{CAD9}', '\u{CAF3}', GraphemeClusterBreak::LVT),
+    ('\u{CAF4}', '\u{CAF4}', GraphemeClusterBreak::LV),
+    ('\u{CAF5}', '\u{CB0F}', GraphemeClusterBreak::LVT),
+    ('\u{CB10}', '\u{CB10}', GraphemeClusterBreak::LV),
+    ('\u{CB11}', '\u{CB2B}', GraphemeClusterBreak::LVT),
+    ('\u{CB2C}', '\u{CB2C}', GraphemeClusterBreak::LV),
+    ('\u{CB2D}', '\u{CB47}', GraphemeClusterBreak::LVT),
+    ('\u{CB48}', '\u{CB48}', GraphemeClusterBreak::LV),
+    ('\u{CB49}', '\u{CB63}', GraphemeClusterBreak::LVT),
+    ('\u{CB64}', '\u{CB64}', GraphemeClusterBreak::LV),
+    ('\u{CB65}', '\u{CB7F}', GraphemeClusterBreak::LVT),
+    ('\u{CB80}', '\u{CB80}', GraphemeClusterBreak::LV),
+    ('\u{CB81}', '\u{CB9B}', GraphemeClusterBreak::LVT),
+    ('\u{CB9C}', '\u{CB9C}', GraphemeClusterBreak::LV),
+    ('\u{CB9D}', '\u{CBB7}', GraphemeClusterBreak::LVT),
+    ('\u{CBB8}', '\u{CBB8}', GraphemeClusterBreak::LV),
+    ('\u{CBB9}', '\u{CBD3}', GraphemeClusterBreak::LVT),
+    ('\u{CBD4}', '\u{CBD4}', GraphemeClusterBreak::LV),
+    ('\u{CBD5}', '\u{CBEF}', GraphemeClusterBreak::LVT),
+    ('\u{CBF0}', '\u{CBF0}', GraphemeClusterBreak::LV),
+    ('\u{CBF1}', '\u{CC0B}', GraphemeClusterBreak::LVT),
+    ('\u{CC0C}', '\u{CC0C}', GraphemeClusterBreak::LV),
+    ('\u{CC0D}', '\u{CC27}', GraphemeClusterBreak::LVT),
+    ('\u{CC28}', '\u{CC28}', GraphemeClusterBreak::LV),
+    ('\u{CC29}', '\u{CC43}', GraphemeClusterBreak::LVT),
+    ('\u{CC44}', '\u{CC44}', GraphemeClusterBreak::LV),
+    ('\u{CC45}', '\u{CC5F}', GraphemeClusterBreak::LVT),
+    ('\u{CC60}', '\u{CC60}', GraphemeClusterBreak::LV),
+    ('\u{CC61}', '\u{CC7B}', GraphemeClusterBreak::LVT),
+    ('\u{CC7C}', '\u{CC7C}', GraphemeClusterBreak::LV),
+    ('\u{CC7D}', '\u{CC97}', GraphemeClusterBreak::LVT),
+    ('\u{CC98}', '\u{CC98}', GraphemeClusterBreak::LV),
+    ('\u{CC99}', '\u{CCB3}', GraphemeClusterBreak::LVT),
+    ('\u{CCB4}', '\u{CCB4}', GraphemeClusterBreak::LV),
+    ('\u{CCB5}', '\u{CCCF}', GraphemeClusterBreak::LVT),
+    ('\u{CCD0}', '\u{CCD0}', GraphemeClusterBreak::LV),
+    ('\u{CCD1}', '\u{CCEB}', GraphemeClusterBreak::LVT),
+    ('\u{CCEC}', '\u{CCEC}', GraphemeClusterBreak::LV),
+    ('\u{CCED}', '\u{CD07}', GraphemeClusterBreak::LVT),
+    ('\u{CD08}', '\u{CD08}', GraphemeClusterBreak::LV),
+    ('\u{CD09}', '\u{CD23}', GraphemeClusterBreak::LVT),
+    ('\u{CD24}', '\u{CD24}', GraphemeClusterBreak::LV),
+    ('\u{CD25}', '\u{CD3F}', GraphemeClusterBreak::LVT),
+    ('\u{CD40}', '\u{CD40}', GraphemeClusterBreak::LV),
+    ('\u{CD41}', '\u{CD5B}', GraphemeClusterBreak::LVT),
+    ('\u{CD5C}', '\u{CD5C}', GraphemeClusterBreak::LV),
+    ('\u{CD5D}', '\u{CD77}', GraphemeClusterBreak::LVT),
+    ('\u{CD78}', '\u{CD78}', GraphemeClusterBreak::LV),
+    ('\u{CD79}', '\u{CD93}', GraphemeClusterBreak::LVT),
+    ('\u{CD94}', '\u{CD94}', GraphemeClusterBreak::LV),
+    ('\u{CD95}', '\u{CDAF}', GraphemeClusterBreak::LVT),
+    ('\u{CDB0}', '\u{CDB0}', GraphemeClusterBreak::LV),
+    ('\u{CDB1}', '\u{CDCB}', GraphemeClusterBreak::LVT),
+    ('\u{CDCC}', '\u{CDCC}', GraphemeClusterBreak::LV),
+    ('\u{CDCD}', '\u{CDE7}', GraphemeClusterBreak::LVT),
+    ('\u{CDE8}', '\u{CDE8}', GraphemeClusterBreak::LV),
+    ('\u{CDE9}', '\u{CE03}', GraphemeClusterBreak::LVT),
+    ('\u{CE04}', '\u{CE04}', GraphemeClusterBreak::LV),
+    ('\u{CE05}', '\u{CE1F}', GraphemeClusterBreak::LVT),
+    ('\u{CE20}', '\u{CE20}', GraphemeClusterBreak::LV),
+    ('\u{CE21}', '\u{CE3B}', GraphemeClusterBreak::LVT),
+    ('\u{CE3C}', '\u{CE3C}', GraphemeClusterBreak::LV),
+    ('\u{CE3D}', '\u{CE57}', GraphemeClusterBreak::LVT),
+    ('\u{CE58}', '\u{CE58}', GraphemeClusterBreak::LV),
+    ('\u{CE59}', '\u{CE73}', GraphemeClusterBreak::LVT),
+    ('\u{CE74}', '\u{CE74}', GraphemeClusterBreak::LV),
+    ('\u{CE75}', '\u{CE8F}', GraphemeClusterBreak::LVT),
+    ('\u{CE90}', '\u{CE90}', GraphemeClusterBreak::LV),
+    ('\u{CE91}', '\u{CEAB}', GraphemeClusterBreak::LVT),
+    ('\u{CEAC}', '\u{CEAC}', GraphemeClusterBreak::LV),
+    ('\u{CEAD}', '\u{CEC7}', GraphemeClusterBreak::LVT),
+    ('\u{CEC8}', '\u{CEC8}', GraphemeClusterBreak::LV),
+    ('\u{CEC9}', '\u{CEE3}', GraphemeClusterBreak::LVT),
+    ('\u{CEE4}', '\u{CEE4}', GraphemeClusterBreak::LV),
+    ('\u{CEE5}', '\u{CEFF}', GraphemeClusterBreak::LVT),
+    ('\u{CF00}', '\u{CF00}', GraphemeClusterBreak::LV),
+    ('\u{CF01}', '\u{CF1B}', GraphemeClusterBreak::LVT),
+    ('\u{CF1C}', '\u{CF1C}', GraphemeClusterBreak::LV),
+    ('\u{CF1D}', '\u{CF37}', GraphemeClusterBreak::LVT),
+    ('\u{CF38}', '\u{CF38}', GraphemeClusterBreak::LV),
+    ('\u{CF39}', '\u{CF53}', GraphemeClusterBreak::LVT),
+    ('\u{CF54}', '\u{CF54}', GraphemeClusterBreak::LV),
+    ('\u{CF55}', '\u{CF6F}', GraphemeClusterBreak::LVT),
+    ('\u{CF70}', '\u{CF70}', GraphemeClusterBreak::LV),
+    ('\u{CF71}', '\u{CF8B}', GraphemeClusterBreak::LVT),
+    ('\u{CF8C}', '\u{CF8C}', GraphemeClusterBreak::LV),
+    ('\u{CF8D}', '\u{CFA7}', GraphemeClusterBreak::LVT),
+    ('\u{CFA8}', '\u{CFA8}', GraphemeClusterBreak::LV),
+    ('\u{CFA9}', '\u{CFC3}', GraphemeClusterBreak::LVT),
+    ('\u{CFC4}', '\u{CFC4}', GraphemeClusterBreak::LV),
+    ('\u{CFC5}', '\u{CFDF}', GraphemeClusterBreak::LVT),
+    ('\u{CFE0}', '\u{CFE0}', GraphemeClusterBreak::LV),
+    ('\u{CFE1}', '\u{CFFB}', GraphemeClusterBreak::LVT),
+    ('\u{CFFC}', '\u{CFFC}', GraphemeClusterBreak::LV),
+    ('\u{CFFD}', '\u{D017}', GraphemeClusterBreak::LVT),
+    ('\u{D018}', '\u{D018}', GraphemeClusterBreak::LV),
+    ('\u{D019}', '\u{D033}', GraphemeClusterBreak::LVT),
+    ('\u{D034}', '\u{D034}', GraphemeClusterBreak::LV),
+    ('\u{D035}', '\u{D04F}', GraphemeClusterBreak::LVT),
+    ('\u{D050}', '\u{D050}', GraphemeClusterBreak::LV),
+    ('\u{D051}', '\u{D06B}', GraphemeClusterBreak::LVT),
+    ('\u{D06C}', '\u{D06C}', GraphemeClusterBreak::LV),
+    ('\u{D06D}', '\u{D087}', GraphemeClusterBreak::LVT),
+    ('\u{D088}', '\u{D088}', GraphemeClusterBreak::LV),
+    ('\u{D089}', '\u{D0A3}', GraphemeClusterBreak::LVT),
+    ('\u{D0A4}', '\u{D0A4}', GraphemeClusterBreak::LV),
+    ('\u{D0A5}', '\u{D0BF}', GraphemeClusterBreak::LVT),
+    ('\u{D0C0}', '\u{D0C0}', GraphemeClusterBreak::LV),
+    ('\u{D0C1}', '\u{D0DB}', GraphemeClusterBreak::LVT),
+    ('\u{D0DC}', '\u{D0DC}', GraphemeClusterBreak::LV),
+    ('\u{D0DD}', '\u{D0F7}', GraphemeClusterBreak::LVT),
+    ('\u{D0F8}', '\u{D0F8}', GraphemeClusterBreak::LV),
+    ('\u{D0F9}', '\u{D113}', GraphemeClusterBreak::LVT),
+    ('\u{D114}', '\u{D114}', GraphemeClusterBreak::LV),
+    ('\u{D115}', '\u{D12F}', GraphemeClusterBreak::LVT),
+    ('\u{D130}', '\u{D130}', GraphemeClusterBreak::LV),
+    ('\u{D131}', '\u{D14B}', GraphemeClusterBreak::LVT),
+    ('\u{D14C}', '\u{D14C}', GraphemeClusterBreak::LV),
+    ('\u{D14D}', '\u{D167}', GraphemeClusterBreak::LVT),
+    ('\u{D168}', '\u{D168}', GraphemeClusterBreak::LV),
+    ('\u{D169}', '\u{D183}', GraphemeClusterBreak::LVT),
+    ('\u{D184}', '\u{D184}', GraphemeClusterBreak::LV),
+    ('\u{D185}', '\u{D19F}', GraphemeClusterBreak::LVT),
+    ('\u{D1A0}', '\u{D1A0}', GraphemeClusterBreak::LV),
+    ('\u{D1A1}', '\u{D1BB}', GraphemeClusterBreak::LVT),
+    ('\u{D1BC}', '\u{D1BC}', GraphemeClusterBreak::LV),
+    ('\u{D1BD}', '\u{D1D7}', GraphemeClusterBreak::LVT),
+    ('\u{D1D8}', '\u{D1D8}', GraphemeClusterBreak::LV),
+    ('\u{D1D9}', '\u{D1F3}', GraphemeClusterBreak::LVT),
+    ('\u{D1F4}', '\u{D1F4}', GraphemeClusterBreak::LV),
+    ('\u{D1F5}', '\u{D20F}', GraphemeClusterBreak::LVT),
+    ('\u{D210}', '\u{D210}', GraphemeClusterBreak::LV),
+    ('\u{D211}', '\u{D22B}', GraphemeClusterBreak::LVT),
+    ('\u{D22C}', '\u{D22C}', GraphemeClusterBreak::LV),
+    ('\u{D22D}', '\u{D247}', GraphemeClusterBreak::LVT),
+    ('\u{D248}', '\u{D248}', GraphemeClusterBreak::LV),
+    ('\u{D249}', '\u{D263}', GraphemeClusterBreak::LVT),
+    ('\u{D264}', '\u{D264}', GraphemeClusterBreak::LV),
+    ('\u{D265}', '\u{D27F}', GraphemeClusterBreak::LVT),
+    ('\u{D280}', '\u{D280}', GraphemeClusterBreak::LV),
+    ('\u{D281}', '\u{D29B}', GraphemeClusterBreak::LVT),
+    ('\u{D29C}', '\u{D29C}', GraphemeClusterBreak::LV),
+    ('\u{D29D}', '\u{D2B7}', GraphemeClusterBreak::LVT),
+    ('\u{D2B8}', '\u{D2B8}', GraphemeClusterBreak::LV),
+    ('\u{D2B9}', '\u{D2D3}', GraphemeClusterBreak::LVT),
+    ('\u{D2D4}', '\u{D2D4}', GraphemeClusterBreak::LV),
+    ('\u{D2D5}', '\u{D2EF}', GraphemeClusterBreak::LVT),
+    ('\u{D2F0}', '\u{D2F0}', GraphemeClusterBreak::LV),
+    ('\u{D2F1}', '\u{D30B}', GraphemeClusterBreak::LVT),
+    ('\u{D30C}', '\u{D30C}', GraphemeClusterBreak::LV),
+    ('\u{D30D}', '\u{D327}', GraphemeClusterBreak::LVT),
+    ('\u{D328}', '\u{D328}', GraphemeClusterBreak::LV),
+    ('\u{D329}', '\u{D343}', GraphemeClusterBreak::LVT),
+    ('\u{D344}', '\u{D344}', GraphemeClusterBreak::LV),
+    ('\u{D345}', '\u{D35F}', GraphemeClusterBreak::LVT),
+    ('\u{D360}', '\u{D360}', GraphemeClusterBreak::LV),
+    ('\u{D361}', '\u{D37B}', GraphemeClusterBreak::LVT),
+    ('\u{D37C}', '\u{D37C}', GraphemeClusterBreak::LV),
+    ('\u{D37D}', '\u{D397}', GraphemeClusterBreak::LVT),
+    ('\u{D398}', '\u{D398}', GraphemeClusterBreak::LV),
+    ('\u{D399}', '\u{D3B3}', GraphemeClusterBreak::LVT),
+    ('\u{D3B4}', '\u{D3B4}', GraphemeClusterBreak::LV),
+    ('\u{D3B5}', '\u{D3CF}', GraphemeClusterBreak::LVT),
+    ('\u{D3D0}', '\u{D3D0}', GraphemeClusterBreak::LV),
+    ('\u{D3D1}', '\u{D3EB}', GraphemeClusterBreak::LVT),
+    ('\u{D3EC}', '\u{D3EC}', GraphemeClusterBreak::LV),
+    ('\u{D3ED}', '\u{D407}', GraphemeClusterBreak::LVT),
+    ('\u{D408}', '\u{D408}', GraphemeClusterBreak::LV),
+    ('\u{D409}', '\u{D423}', GraphemeClusterBreak::LVT),
+    ('\u{D424}', '\u{D424}', GraphemeClusterBreak::LV),
+    ('\u{D425}', '\u{D43F}', GraphemeClusterBreak::LVT),
+    ('\u{D440}', '\u{D440}', GraphemeClusterBreak::LV),
+    ('\u{D441}', '\u{D45B}', GraphemeClusterBreak::LVT),
+    ('\u{D45C}', '\u{D45C}', GraphemeClusterBreak::LV),
+    ('\u{D45D}', '\u{D477}', GraphemeClusterBreak::LVT),
+    ('\u{D478}', '\u{D478}', GraphemeClusterBreak::LV),
+    ('\u{D479}', '\u{D493}', GraphemeClusterBreak::LVT),
+    ('\u{D494}', '\u{D494}', GraphemeClusterBreak::LV),
+    ('\u{D495}', '\u{D4AF}', GraphemeClusterBreak::LVT),
+    ('\u{D4B0}', '\u{D4B0}', GraphemeClusterBreak::LV),
+    ('\u{D4B1}', '\u{D4CB}', GraphemeClusterBreak::LVT),
+    ('\u{D4CC}', '\u{D4CC}', GraphemeClusterBreak::LV),
+    ('\u{D4CD}', '\u{D4E7}', GraphemeClusterBreak::LVT),
+    ('\u{D4E8}', '\u{D4E8}', GraphemeClusterBreak::LV),
+    ('\u{D4E9}', '\u{D503}', GraphemeClusterBreak::LVT),
+    ('\u{D504}', '\u{D504}', GraphemeClusterBreak::LV),
+    ('\u{D505}', '\u{D51F}', GraphemeClusterBreak::LVT),
+    ('\u{D520}', '\u{D520}', GraphemeClusterBreak::LV),
+    ('\u{D521}', '\u{D53B}', GraphemeClusterBreak::LVT),
+    ('\u{D53C}', '\u{D53C}', GraphemeClusterBreak::LV),
+    ('\u{D53D}', '\u{D557}', GraphemeClusterBreak::LVT),
+    ('\u{D558}', '\u{D558}', GraphemeClusterBreak::LV),
+    ('\u{D559}', '\u{D573}', GraphemeClusterBreak::LVT),
+    ('\u{D574}', '\u{D574}', GraphemeClusterBreak::LV),
+    ('\u{D575}', '\u{D58F}', GraphemeClusterBreak::LVT),
+    ('\u{D590}', '\u{D590}', GraphemeClusterBreak::LV),
+    ('\u{D591}', '\u{D5AB}', GraphemeClusterBreak::LVT),
+    ('\u{D5AC}', '\u{D5AC}', GraphemeClusterBreak::LV),
+    ('\u{D5AD}', '\u{D5C7}', GraphemeClusterBreak::LVT),
+    ('\u{D5C8}', '\u{D5C8}', GraphemeClusterBreak::LV),
+    ('\u{D5C9}', '\u{D5E3}', GraphemeClusterBreak::LVT),
+    ('\u{D5E4}', '\u{D5E4}', GraphemeClusterBreak::LV),
+    ('\u{D5E5}', '\u{D5FF}', GraphemeClusterBreak::LVT),
+    ('\u{D600}', '\u{D600}', GraphemeClusterBreak::LV),
+    ('\u{D601}', '\u{D61B}', GraphemeClusterBreak::LVT),
+    ('\u{D61C}', '\u{D61C}', GraphemeClusterBreak::LV),
+    ('\u{D61D}', '\u{D637}', GraphemeClusterBreak::LVT),
+    ('\u{D638}', '\u{D638}', GraphemeClusterBreak::LV),
+    ('\u{D639}', '\u{D653}', GraphemeClusterBreak::LVT),
+    ('\u{D654}', '\u{D654}', GraphemeClusterBreak::LV),
+    ('\u{D655}', '\u{D66F}', GraphemeClusterBreak::LVT),
+    ('\u{D670}', '\u{D670}', GraphemeClusterBreak::LV),
+    ('\u{D671}', '\u{D68B}', GraphemeClusterBreak::LVT),
+    ('\u{D68C}', '\u{D68C}', GraphemeClusterBreak::LV),
+    ('\u{D68D}', '\u{D6A7}', GraphemeClusterBreak::LVT),
+    ('\u{D6A8}', '\u{D6A8}', GraphemeClusterBreak::LV),
+    ('\u{D6A9}', '\u{D6C3}', GraphemeClusterBreak::LVT),
+    ('\u{D6C4}', '\u{D6C4}', GraphemeClusterBreak::LV),
+    ('\u{D6C5}', '\u{D6DF}', GraphemeClusterBreak::LVT),
+    ('\u{D6E0}', '\u{D6E0}', GraphemeClusterBreak::LV),
+    ('\u{D6E1}', '\u{D6FB}', GraphemeClusterBreak::LVT),
+    ('\u{D6FC}', '\u{D6FC}', GraphemeClusterBreak::LV),
+    ('\u{D6FD}', '\u{D717}', GraphemeClusterBreak::LVT),
+    ('\u{D718}', '\u{D718}', GraphemeClusterBreak::LV),
+    ('\u{D719}', '\u{D733}', GraphemeClusterBreak::LVT),
+    ('\u{D734}', '\u{D734}', GraphemeClusterBreak::LV),
+    ('\u{D735}', '\u{D74F}', GraphemeClusterBreak::LVT),
+    ('\u{D750}', '\u{D750}', GraphemeClusterBreak::LV),
+    ('\u{D751}', '\u{D76B}', GraphemeClusterBreak::LVT),
+    ('\u{D76C}', '\u{D76C}', GraphemeClusterBreak::LV),
+    ('\u{D76D}', '\u{D787}', GraphemeClusterBreak::LVT),
+    ('\u{D788}', '\u{D788}', GraphemeClusterBreak::LV),
+    ('\u{D789}', '\u{D7A3}', GraphemeClusterBreak::LVT),
+    ('\u{D7B0}', '\u{D7C6}', GraphemeClusterBreak::V),
+    ('\u{D7CB}', '\u{D7FB}', GraphemeClusterBreak::T),
+    ('\u{FB1E}', '\u{FB1E}', GraphemeClusterBreak::Extend),
+    ('\u{FE00}', '\u{FE0F}', GraphemeClusterBreak::Extend),
+    ('\u{FE20}', '\u{FE2F}', GraphemeClusterBreak::Extend),
+    ('\u{FEFF}', '\u{FEFF}', GraphemeClusterBreak::Control),
+    ('\u{FF9E}', '\u{FF9F}', GraphemeClusterBreak::Extend),
+    ('\u{FFF0}', '\u{FFFB}', GraphemeClusterBreak::Control),
+    ('\u{101FD}', '\u{101FD}', GraphemeClusterBreak::Extend),
+    ('\u{102E0}', '\u{102E0}', GraphemeClusterBreak::Extend),
+    ('\u{10376}', '\u{1037A}', GraphemeClusterBreak::Extend),
+    ('\u{10A01}', '\u{10A03}', GraphemeClusterBreak::Extend),
+    ('\u{10A05}', '\u{10A06}', GraphemeClusterBreak::Extend),
+    ('\u{10A0C}', '\u{10A0F}', GraphemeClusterBreak::Extend),
+    ('\u{10A38}', '\u{10A3A}', GraphemeClusterBreak::Extend),
+    ('\u{10A3F}', '\u{10A3F}', GraphemeClusterBreak::Extend),
+    ('\u{10AE5}', '\u{10AE6}', GraphemeClusterBreak::Extend),
+    ('\u{10D24}', '\u{10D27}', GraphemeClusterBreak::Extend),
+    ('\u{10EAB}', '\u{10EAC}', GraphemeClusterBreak::Extend),
+    ('\u{10F46}', '\u{10F50}', GraphemeClusterBreak::Extend),
+    ('\u{10F82}', '\u{10F85}', GraphemeClusterBreak::Extend),
+    ('\u{11000}', '\u{11000}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11001}', '\u{11001}', GraphemeClusterBreak::Extend),
+    ('\u{11002}', '\u{11002}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11038}', '\u{11046}', GraphemeClusterBreak::Extend),
+    ('\u{11070}', '\u{11070}', GraphemeClusterBreak::Extend),
+    ('\u{11073}', '\u{11074}', GraphemeClusterBreak::Extend),
+    ('\u{1107F}', '\u{11081}', GraphemeClusterBreak::Extend),
+    ('\u{11082}', '\u{11082}', GraphemeClusterBreak::SpacingMark),
+    ('\u{110B0}', '\u{110B2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{110B3}', '\u{110B6}', GraphemeClusterBreak::Extend),
+    ('\u{110B7}', '\u{110B8}', GraphemeClusterBreak::SpacingMark),
+    ('\u{110B9}', '\u{110BA}', GraphemeClusterBreak::Extend),
+    ('\u{110BD}', '\u{110BD}', GraphemeClusterBreak::Prepend),
+    ('\u{110C2}', '\u{110C2}', GraphemeClusterBreak::Extend),
+    ('\u{110CD}', '\u{110CD}', GraphemeClusterBreak::Prepend),
+    ('\u{11100}', '\u{11102}', GraphemeClusterBreak::Extend),
+    ('\u{11127}', '\u{1112B}', GraphemeClusterBreak::Extend),
+    ('\u{1112C}', '\u{1112C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1112D}', '\u{11134}', GraphemeClusterBreak::Extend),
+    ('\u{11145}', '\u{11146}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11173}', '\u{11173}', GraphemeClusterBreak::Extend),
+    ('\u{11180}', '\u{11181}', GraphemeClusterBreak::Extend),
+    ('\u{11182}', '\u{11182}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111B3}', '\u{111B5}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111B6}', '\u{111BE}', GraphemeClusterBreak::Extend),
+    ('\u{111BF}', '\u{111C0}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111C2}', '\u{111C3}', GraphemeClusterBreak::Prepend),
+    ('\u{111C9}', '\u{111CC}', GraphemeClusterBreak::Extend),
+    ('\u{111CE}', '\u{111CE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{111CF}', '\u{111CF}', GraphemeClusterBreak::Extend),
+    ('\u{1122C}', '\u{1122E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1122F}', '\u{11231}', GraphemeClusterBreak::Extend),
+    ('\u{11232}', '\u{11233}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11234}', '\u{11234}', GraphemeClusterBreak::Extend),
+    ('\u{11235}', '\u{11235}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11236}', '\u{11237}', GraphemeClusterBreak::Extend),
+    ('\u{1123E}', '\u{1123E}', GraphemeClusterBreak::Extend),
+    ('\u{112DF}', '\u{112DF}', GraphemeClusterBreak::Extend),
+    ('\u{112E0}', '\u{112E2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{112E3}', '\u{112EA}', GraphemeClusterBreak::Extend),
+    ('\u{11300}', '\u{11301}', GraphemeClusterBreak::Extend),
+    ('\u{11302}', '\u{11303}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1133B}', '\u{1133C}', GraphemeClusterBreak::Extend),
+    ('\u{1133E}', '\u{1133E}', GraphemeClusterBreak::Extend),
+    ('\u{1133F}', '\u{1133F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11340}', '\u{11340}', GraphemeClusterBreak::Extend),
+    ('\u{11341}', '\u{11344}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11347}', '\u{11348}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1134B}', '\u{1134D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11357}', '\u{11357}', GraphemeClusterBreak::Extend),
+    ('\u{11362}', '\u{11363}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11366}', '\u{1136C}', GraphemeClusterBreak::Extend),
+    ('\u{11370}', '\u{11374}', GraphemeClusterBreak::Extend),
+    ('\u{11435}', '\u{11437}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11438}', '\u{1143F}', GraphemeClusterBreak::Extend),
+    ('\u{11440}', '\u{11441}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11442}', '\u{11444}', GraphemeClusterBreak::Extend),
+    ('\u{11445}', '\u{11445}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11446}', '\u{11446}', GraphemeClusterBreak::Extend),
+    ('\u{1145E}', '\u{1145E}', GraphemeClusterBreak::Extend),
+    ('\u{114B0}', '\u{114B0}', GraphemeClusterBreak::Extend),
+    ('\u{114B1}', '\u{114B2}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114B3}', '\u{114B8}', GraphemeClusterBreak::Extend),
+    ('\u{114B9}', '\u{114B9}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114BA}', '\u{114BA}', GraphemeClusterBreak::Extend),
+    ('\u{114BB}', '\u{114BC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114BD}', '\u{114BD}', GraphemeClusterBreak::Extend),
+    ('\u{114BE}', '\u{114BE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114BF}', '\u{114C0}', GraphemeClusterBreak::Extend),
+    ('\u{114C1}', '\u{114C1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{114C2}', '\u{114C3}', GraphemeClusterBreak::Extend),
+    ('\u{115AF}', '\u{115AF}', GraphemeClusterBreak::Extend),
+    ('\u{115B0}', '\u{115B1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115B2}', '\u{115B5}', GraphemeClusterBreak::Extend),
+    ('\u{115B8}', '\u{115BB}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115BC}', '\u{115BD}', GraphemeClusterBreak::Extend),
+    ('\u{115BE}', '\u{115BE}', GraphemeClusterBreak::SpacingMark),
+    ('\u{115BF}', '\u{115C0}', GraphemeClusterBreak::Extend),
+    ('\u{115DC}', '\u{115DD}', GraphemeClusterBreak::Extend),
+    ('\u{11630}', '\u{11632}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11633}', '\u{1163A}', GraphemeClusterBreak::Extend),
+    ('\u{1163B}', '\u{1163C}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1163D}', '\u{1163D}', GraphemeClusterBreak::Extend),
+    ('\u{1163E}', '\u{1163E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1163F}', '\u{11640}', GraphemeClusterBreak::Extend),
+    ('\u{116AB}', '\u{116AB}', GraphemeClusterBreak::Extend),
+    ('\u{116AC}', '\u{116AC}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116AD}', '\u{116AD}', GraphemeClusterBreak::Extend),
+    ('\u{116AE}', '\u{116AF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116B0}', '\u{116B5}', GraphemeClusterBreak::Extend),
+    ('\u{116B6}', '\u{116B6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{116B7}', '\u{116B7}', GraphemeClusterBreak::Extend),
+    ('\u{1171D}', '\u{1171F}', GraphemeClusterBreak::Extend),
+    ('\u{11722}', '\u{11725}', GraphemeClusterBreak::Extend),
+    ('\u{11726}', '\u{11726}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11727}', '\u{1172B}', GraphemeClusterBreak::Extend),
+    ('\u{1182C}', '\u{1182E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1182F}', '\u{11837}', GraphemeClusterBreak::Extend),
+    ('\u{11838}', '\u{11838}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11839}', '\u{1183A}', GraphemeClusterBreak::Extend),
+    ('\u{11930}', '\u{11930}', GraphemeClusterBreak::Extend),
+    ('\u{11931}', '\u{11935}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11937}', '\u{11938}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1193B}', '\u{1193C}', GraphemeClusterBreak::Extend),
+    ('\u{1193D}', '\u{1193D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1193E}', '\u{1193E}', GraphemeClusterBreak::Extend),
+    ('\u{1193F}', '\u{1193F}', GraphemeClusterBreak::Prepend),
+    ('\u{11940}', '\u{11940}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11941}', '\u{11941}', GraphemeClusterBreak::Prepend),
+    ('\u{11942}', '\u{11942}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11943}', '\u{11943}', GraphemeClusterBreak::Extend),
+    ('\u{119D1}', '\u{119D3}', GraphemeClusterBreak::SpacingMark),
+    ('\u{119D4}', '\u{119D7}', GraphemeClusterBreak::Extend),
+    ('\u{119DA}', '\u{119DB}', GraphemeClusterBreak::Extend),
+    ('\u{119DC}', '\u{119DF}', GraphemeClusterBreak::SpacingMark),
+    ('\u{119E0}', '\u{119E0}', GraphemeClusterBreak::Extend),
+    ('\u{119E4}', '\u{119E4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A01}', '\u{11A0A}', GraphemeClusterBreak::Extend),
+    ('\u{11A33}', '\u{11A38}', GraphemeClusterBreak::Extend),
+    ('\u{11A39}', '\u{11A39}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A3A}', '\u{11A3A}', GraphemeClusterBreak::Prepend),
+    ('\u{11A3B}', '\u{11A3E}', GraphemeClusterBreak::Extend),
+    ('\u{11A47}', '\u{11A47}', GraphemeClusterBreak::Extend),
+    ('\u{11A51}', '\u{11A56}', GraphemeClusterBreak::Extend),
+    ('\u{11A57}', '\u{11A58}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A59}', '\u{11A5B}', GraphemeClusterBreak::Extend),
+    ('\u{11A84}', '\u{11A89}', GraphemeClusterBreak::Prepend),
+    ('\u{11A8A}', '\u{11A96}', GraphemeClusterBreak::Extend),
+    ('\u{11A97}', '\u{11A97}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11A98}', '\u{11A99}', GraphemeClusterBreak::Extend),
+    ('\u{11C2F}', '\u{11C2F}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11C30}', '\u{11C36}', GraphemeClusterBreak::Extend),
+    ('\u{11C38}', '\u{11C3D}', GraphemeClusterBreak::Extend),
+    ('\u{11C3E}', '\u{11C3E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11C3F}', '\u{11C3F}', GraphemeClusterBreak::Extend),
+    ('\u{11C92}', '\u{11CA7}', GraphemeClusterBreak::Extend),
+    ('\u{11CA9}', '\u{11CA9}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11CAA}', '\u{11CB0}', GraphemeClusterBreak::Extend),
+    ('\u{11CB1}', '\u{11CB1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11CB2}', '\u{11CB3}', GraphemeClusterBreak::Extend),
+    ('\u{11CB4}', '\u{11CB4}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11CB5}', '\u{11CB6}', GraphemeClusterBreak::Extend),
+    ('\u{11D31}', '\u{11D36}', GraphemeClusterBreak::Extend),
+    ('\u{11D3A}', '\u{11D3A}', GraphemeClusterBreak::Extend),
+    ('\u{11D3C}', '\u{11D3D}', GraphemeClusterBreak::Extend),
+    ('\u{11D3F}', '\u{11D45}', GraphemeClusterBreak::Extend),
+    ('\u{11D46}', '\u{11D46}', GraphemeClusterBreak::Prepend),
+    ('\u{11D47}', '\u{11D47}', GraphemeClusterBreak::Extend),
+    ('\u{11D8A}', '\u{11D8E}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11D90}', '\u{11D91}', GraphemeClusterBreak::Extend),
+    ('\u{11D93}', '\u{11D94}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11D95}', '\u{11D95}', GraphemeClusterBreak::Extend),
+    ('\u{11D96}', '\u{11D96}', GraphemeClusterBreak::SpacingMark),
+    ('\u{11D97}', '\u{11D97}', GraphemeClusterBreak::Extend),
+    ('\u{11EF3}', '\u{11EF4}', GraphemeClusterBreak::Extend),
+    ('\u{11EF5}', '\u{11EF6}', GraphemeClusterBreak::SpacingMark),
+    ('\u{13430}', '\u{13438}', GraphemeClusterBreak::Control),
+    ('\u{16AF0}', '\u{16AF4}', GraphemeClusterBreak::Extend),
+    ('\u{16B30}', '\u{16B36}', GraphemeClusterBreak::Extend),
+    ('\u{16F4F}', '\u{16F4F}', GraphemeClusterBreak::Extend),
+    ('\u{16F51}', '\u{16F87}', GraphemeClusterBreak::SpacingMark),
+    ('\u{16F8F}', '\u{16F92}', GraphemeClusterBreak::Extend),
+    ('\u{16FE4}', '\u{16FE4}', GraphemeClusterBreak::Extend),
+    ('\u{16FF0}', '\u{16FF1}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1BC9D}', '\u{1BC9E}', GraphemeClusterBreak::Extend),
+    ('\u{1BCA0}', '\u{1BCA3}', GraphemeClusterBreak::Control),
+    ('\u{1CF00}', '\u{1CF2D}', GraphemeClusterBreak::Extend),
+    ('\u{1CF30}', '\u{1CF46}', GraphemeClusterBreak::Extend),
+    ('\u{1D165}', '\u{1D165}', GraphemeClusterBreak::Extend),
+    ('\u{1D166}', '\u{1D166}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1D167}', '\u{1D169}', GraphemeClusterBreak::Extend),
+    ('\u{1D16D}', '\u{1D16D}', GraphemeClusterBreak::SpacingMark),
+    ('\u{1D16E}', '\u{1D172}', GraphemeClusterBreak::Extend),
+    ('\u{1D173}', '\u{1D17A}', GraphemeClusterBreak::Control),
+    ('\u{1D17B}', '\u{1D182}', GraphemeClusterBreak::Extend),
+    ('\u{1D185}', '\u{1D18B}', GraphemeClusterBreak::Extend),
+    ('\u{1D1AA}', '\u{1D1AD}', GraphemeClusterBreak::Extend),
+    ('\u{1D242}', '\u{1D244}', GraphemeClusterBreak::Extend),
+    ('\u{1DA00}', '\u{1DA36}', GraphemeClusterBreak::Extend),
+    ('\u{1DA3B}', '\u{1DA6C}', GraphemeClusterBreak::Extend),
+    ('\u{1DA75}', '\u{1DA75}', GraphemeClusterBreak::Extend),
+    ('\u{1DA84}', '\u{1DA84}', GraphemeClusterBreak::Extend),
+    ('\u{1DA9B}', '\u{1DA9F}', GraphemeClusterBreak::Extend),
+    ('\u{1DAA1}', '\u{1DAAF}', GraphemeClusterBreak::Extend),
+    ('\u{1E000}', '\u{1E006}', GraphemeClusterBreak::Extend),
+    ('\u{1E008}', '\u{1E018}', GraphemeClusterBreak::Extend),
+    ('\u{1E01B}', '\u{1E021}', GraphemeClusterBreak::Extend),
+    ('\u{1E023}', '\u{1E024}', GraphemeClusterBreak::Extend),
+    ('\u{1E026}', '\u{1E02A}', GraphemeClusterBreak::Extend),
+    ('\u{1E130}', '\u{1E136}', GraphemeClusterBreak::Extend),
+    ('\u{1E2AE}', '\u{1E2AE}', GraphemeClusterBreak::Extend),
+    ('\u{1E2EC}', '\u{1E2EF}', GraphemeClusterBreak::Extend),
+    ('\u{1E8D0}', '\u{1E8D6}', GraphemeClusterBreak::Extend),
+    ('\u{1E944}', '\u{1E94A}', GraphemeClusterBreak::Extend),
+    ('\u{1F1E6}', '\u{1F1FF}', GraphemeClusterBreak::RegionalIndicator),
+    ('\u{1F3FB}', '\u{1F3FF}', GraphemeClusterBreak::Extend),
+    ('\u{E0000}', '\u{E001F}', GraphemeClusterBreak::Control),
+    ('\u{E0020}', '\u{E007F}', GraphemeClusterBreak::Extend),
+    ('\u{E0080}', '\u{E00FF}', GraphemeClusterBreak::Control),
+    ('\u{E0100}', '\u{E01EF}', GraphemeClusterBreak::Extend),
+    ('\u{E01F0}', '\u{E0FFF}', GraphemeClusterBreak::Control),
+];
+
+/// Characters with the `Extended_Pictographic` property.
+pub(crate) const EXTENDED_PICTOGRAPHIC: &[(char, char)] = &[
+    ('\u{A9}', '\u{A9}'),
+    ('\u{AE}', '\u{AE}'),
+    ('\u{203C}', '\u{203C}'),
+    ('\u{2049}', '\u{2049}'),
+    ('\u{2122}', '\u{2122}'),
+    ('\u{2139}', '\u{2139}'),
+    ('\u{2194}', '\u{2199}'),
+    ('\u{21A9}', '\u{21AA}'),
+    ('\u{231A}', '\u{231B}'),
+    ('\u{2328}', '\u{2328}'),
+    ('\u{2388}', '\u{2388}'),
+    ('\u{23CF}', '\u{23CF}'),
+    ('\u{23E9}', '\u{23F3}'),
+    ('\u{23F8}', '\u{23FA}'),
+    ('\u{24C2}', '\u{24C2}'),
+    ('\u{25AA}', '\u{25AB}'),
+    ('\u{25B6}', '\u{25B6}'),
+    ('\u{25C0}', '\u{25C0}'),
+    ('\u{25FB}', '\u{25FE}'),
+    ('\u{2600}', '\u{2605}'),
+    ('\u{2607}', '\u{2612}'),
+    ('\u{2614}', '\u{2685}'),
+    ('\u{2690}', '\u{2705}'),
+    ('\u{2708}', '\u{2712}'),
+    ('\u{2714}', '\u{2714}'),
+    ('\u{2716}', '\u{2716}'),
+    ('\u{271D}', '\u{271D}'),
+    ('\u{2721}', '\u{2721}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{2733}', '\u{2734}'),
+    ('\u{2744}', '\u{2744}'),
+    ('\u{2747}', '\u{2747}'),
+    ('\u{274C}', '\u{274C}'),
+    ('\u{274E}', '\u{274E}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2763}', '\u{2767}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27A1}', '\u{27A1}'),
+    ('\u{27B0}', '\u{27B0}'),
+    ('\u{27BF}', '\u{27BF}'),
+    ('\u{2934}', '\u{2935}'),
+    ('\u{2B05}', '\u{2B07}'),
+    ('\u{2B1B}', '\u{2B1C}'),
+    ('\u{2B50}', '\u{2B50}'),
+    ('\u{2B55}', '\u{2B55}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303D}', '\u{303D}'),
+    ('\u{3297}', '\u{3297}'),
+    ('\u{3299}', '\u{3299}'),
+    ('\u{1F000}', '\u{1F0FF}'),
+    ('\u{1F10D}', '\u{1F10F}'),
+    ('\u{1F12F}', '\u{1F12F}'),
+    ('\u{1F16C}', '\u{1F171}'),
+    ('\u{1F17E}', '\u{1F17F}'),
+    ('\u{1F18E}', '\u{1F18E}'),
+    ('\u{1F191}', '\u{1F19A}'),
+    ('\u{1F1AD}', '\u{1F1E5}'),
+    ('\u{1F201}', '\u{1F20F}'),
+    ('\u{1F21A}', '\u{1F21A}'),
+    ('\u{1F22F}', '\u{1F22F}'),
+    ('\u{1F232}', '\u{1F23A}'),
+    ('\u{1F23C}', '\u{1F23F}'),
+    ('\u{1F249}', '\u{1F3FA}'),
+    ('\u{1F400}', '\u{1F53D}'),
+    ('\u{1F546}', '\u{1F64F}'),
+    ('\u{1F680}', '\u{1F6FF}'),
+    ('\u{1F774}', '\u{1F77F}'),
+    ('\u{1F7D5}', '\u{1F7FF}'),
+    ('\u{1F80C}', '\u{1F80F}'),
+    ('\u{1F848}', '\u{1F84F}'),
+    ('\u{1F85A}', '\u{1F85F}'),
+    ('\u{1F888}', '\u{1F88F}'),
+    ('\u{1F8AE}', '\u{1F8FF}'),
+    ('\u{1F90C}', '\u{1F93A}'),
+    ('\u{1F93C}', '\u{1F945}'),
+    ('\u{1F947}', '\u{1FAFF}'),
+    ('\u{1FC00}', '\u{1FFFD}'),
+];
@@ -0,0 +1,94 @@
+#[rustfmt::skip]
+pub(crate) mod case;
+#[rustfmt::skip]
+pub(crate) mod escape;
+#[cfg(feature = "unicode-segmentation")]
+#[rustfmt::skip]
+pub(crate) mod grapheme;
+#[cfg(feature = "unicode-normalization")]
+#[rustfmt::skip]
+pub(crate) mod normalize;
+#[cfg(feature = "unicode-width")]
+#[rustfmt::skip]
+pub(crate) mod width;
+
+/// Returns the index of the entry for a character in a table sorted by
+/// character.
+pub(crate) const fn find<T>(table: &[(char, T)], c: char) -> Option<usize> {
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let key = table[mid].0;
+        if key == c {
+            return Some(mid);
+        } else if (key as u32) < (c as u32) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    None
+}
+
+/// Returns the index of the entry for a character in a table of sorted,
+/// inclusive ranges.
+#[cfg(any(feature = "unicode-normalization", feature = "unicode-segmentation"))]
+pub(crate) const fn find_range<T>(table: &[(char, char, T)], c: char) -> Option<usize> {
+    let c = c as u32;
+
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end) = (table[mid].0, table[mid].1);
+        if c < start as u32 {
+            hi = mid;
+        } else if c > end as u32 {
+            lo = mid + 1;
+        } else {
+            return Some(mid);
+        }
+    }
+    None
+}
+
+/// Returns `true` if a character is in a table of sorted, inclusive ranges.
+pub(crate) const fn in_ranges(table: &[(char, char)], c: char) -> bool {
+    let c = c as u32;
+
+    let mut lo = 0;
+    let mut hi = table.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end) = table[mid];
+        if c < start as u32 {
+            hi = mid;
+        } else if c > end as u32 {
+            lo = mid + 1;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns `true` if a character has the `White_Space` property.
+///
+/// The property is small and stable, so it is not generated.
+pub(crate) const fn is_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\u{9}'..='\u{D}'
+            | '\u{20}'
+            | '\u{85}'
+            | '\u{A0}'
+            | '\u{1680}'
+            | '\u{2000}'..='\u{200A}'
+            | '\u{2028}'
+            | '\u{2029}'
+            | '\u{202F}'
+            | '\u{205F}'
+            | '\u{3000}'
+    )
+}
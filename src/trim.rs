@@ -0,0 +1,260 @@
+use crate::buf::Buf;
+use crate::pattern::{CharSet, Needle};
+use crate::utf8;
+
+/// Removes leading and trailing whitespace from a constant `&str`, producing a
+/// constant `&str`.
+///
+/// By default, whitespace is any character with the Unicode `White_Space`
+/// property. A second argument selects the characters to remove instead, and
+/// may be a `char`, a `&[char]`, [`Whitespace`](crate::Whitespace) or
+/// [`AsciiWhitespace`](crate::AsciiWhitespace).
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_trim;
+/// const INPUT: &str = "\u{3000} value\r\n";
+/// const VALUE: &str = chstr_trim!(INPUT);
+///
+/// assert_eq!(VALUE, "value");
+/// ```
+///
+/// Other character sets:
+/// ```
+/// # use chstr::{chstr_trim, AsciiWhitespace};
+/// assert_eq!(chstr_trim!("\u{3000} value\n", AsciiWhitespace), "\u{3000} value");
+/// assert_eq!(chstr_trim!("--value--", '-'), "value");
+/// assert_eq!(chstr_trim!("_-value-_", &['-', '_']), "value");
+/// ```
+#[macro_export]
+macro_rules! chstr_trim {
+    ($s:expr $(,)?) => {
+        $crate::chstr_trim!($s, $crate::Whitespace)
+    };
+    ($s:expr, $set:expr $(,)?) => {{
+        const STR: &str = $s;
+        const SET: $crate::__private::CharSet<'static> =
+            $crate::__private::Pattern($set).char_set();
+
+        $crate::__chstr_build!(|buf| $crate::__private::trim(buf, STR, SET, true, true))
+    }};
+}
+
+/// Removes leading whitespace from a constant `&str`, producing a constant
+/// `&str`.
+///
+/// The characters to remove are selected as by [`chstr_trim!`].
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_trim_start;
+/// const VALUE: &str = chstr_trim_start!("  value  ");
+///
+/// assert_eq!(VALUE, "value  ");
+/// assert_eq!(chstr_trim_start!("0042", '0'), "42");
+/// ```
+#[macro_export]
+macro_rules! chstr_trim_start {
+    ($s:expr $(,)?) => {
+        $crate::chstr_trim_start!($s, $crate::Whitespace)
+    };
+    ($s:expr, $set:expr $(,)?) => {{
+        const STR: &str = $s;
+        const SET: $crate::__private::CharSet<'static> =
+            $crate::__private::Pattern($set).char_set();
+
+        $crate::__chstr_build!(|buf| $crate::__private::trim(buf, STR, SET, true, false))
+    }};
+}
+
+/// Removes trailing whitespace from a constant `&str`, producing a constant
+/// `&str`.
+///
+/// The characters to remove are selected as by [`chstr_trim!`].
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_trim_end;
+/// const VERSION: &str = chstr_trim_end!("1.2.3\n");
+///
+/// assert_eq!(VERSION, "1.2.3");
+/// assert_eq!(chstr_trim_end!("1.5000", '0'), "1.5");
+/// ```
+#[macro_export]
+macro_rules! chstr_trim_end {
+    ($s:expr $(,)?) => {
+        $crate::chstr_trim_end!($s, $crate::Whitespace)
+    };
+    ($s:expr, $set:expr $(,)?) => {{
+        const STR: &str = $s;
+        const SET: $crate::__private::CharSet<'static> =
+            $crate::__private::Pattern($set).char_set();
+
+        $crate::__chstr_build!(|buf| $crate::__private::trim(buf, STR, SET, false, true))
+    }};
+}
+
+/// Removes a prefix from a constant `&str`, producing a constant `&str`.
+///
+/// The prefix may be a `char` or a `&str`. A missing prefix is a compile-time
+/// error, unless the `optional` option is given, in which case the input is
+/// left unchanged.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_strip_prefix;
+/// const URL: &str = "https://example.com";
+/// const HOST: &str = chstr_strip_prefix!(URL, "https://");
+///
+/// assert_eq!(HOST, "example.com");
+/// assert_eq!(chstr_strip_prefix!("v1.2", 'v'), "1.2");
+/// assert_eq!(chstr_strip_prefix!("1.2", 'v', optional), "1.2");
+/// ```
+///
+/// A missing prefix fails to compile:
+/// ```compile_fail
+/// # use chstr::chstr_strip_prefix;
+/// const BAD: &str = chstr_strip_prefix!("http://example.com", "https://");
+/// ```
+#[macro_export]
+macro_rules! chstr_strip_prefix {
+    ($s:expr, $prefix:expr $(,)?) => {{
+        const STR: &str = $s;
+        const PREFIX: $crate::__private::Needle<'static> =
+            $crate::__private::Pattern($prefix).needle();
+
+        $crate::__chstr_build!(|buf| $crate::__private::strip_prefix(buf, STR, PREFIX, true))
+    }};
+    ($s:expr, $prefix:expr, optional $(,)?) => {{
+        const STR: &str = $s;
+        const PREFIX: $crate::__private::Needle<'static> =
+            $crate::__private::Pattern($prefix).needle();
+
+        $crate::__chstr_build!(|buf| $crate::__private::strip_prefix(buf, STR, PREFIX, false))
+    }};
+}
+
+/// Removes a suffix from a constant `&str`, producing a constant `&str`.
+///
+/// The suffix may be a `char` or a `&str`. A missing suffix is a compile-time
+/// error, unless the `optional` option is given, in which case the input is
+/// left unchanged.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use chstr::chstr_strip_suffix;
+/// const FILE: &str = "config.toml";
+/// const NAME: &str = chstr_strip_suffix!(FILE, ".toml");
+///
+/// assert_eq!(NAME, "config");
+/// assert_eq!(chstr_strip_suffix!("line\n", '\n'), "line");
+/// assert_eq!(chstr_strip_suffix!("line", '\n', optional), "line");
+/// ```
+///
+/// A missing suffix fails to compile:
+/// ```compile_fail
+/// # use chstr::chstr_strip_suffix;
+/// const BAD: &str = chstr_strip_suffix!("config.json", ".toml");
+/// ```
+#[macro_export]
+macro_rules! chstr_strip_suffix {
+    ($s:expr, $suffix:expr $(,)?) => {{
+        const STR: &str = $s;
+        const SUFFIX: $crate::__private::Needle<'static> =
+            $crate::__private::Pattern($suffix).needle();
+
+        $crate::__chstr_build!(|buf| $crate::__private::strip_suffix(buf, STR, SUFFIX, true))
+    }};
+    ($s:expr, $suffix:expr, optional $(,)?) => {{
+        const STR: &str = $s;
+        const SUFFIX: $crate::__private::Needle<'static> =
+            $crate::__private::Pattern($suffix).needle();
+
+        $crate::__chstr_build!(|buf| $crate::__private::strip_suffix(buf, STR, SUFFIX, false))
+    }};
+}
+
+/// Writes a string to the buffer without the leading and/or trailing characters
+/// in a set.
+pub const fn trim<const N: usize>(
+    buf: Buf<N>,
+    s: &str,
+    set: CharSet,
+    start: bool,
+    end: bool,
+) -> Buf<N> {
+    let bytes = s.as_bytes();
+
+    let mut i = 0;
+    if start {
+        while i < bytes.len() {
+            let (c, len) = utf8::decode(bytes, i);
+            if !set.contains(c) {
+                break;
+            }
+            i += len;
+        }
+    }
+
+    let mut j = bytes.len();
+    if end {
+        while j > i {
+            let (c, len) = utf8::decode_last(bytes, j);
+            if !set.contains(c) {
+                break;
+            }
+            j -= len;
+        }
+    }
+
+    buf.push_slice(s, i, j)
+}
+
+/// Writes a string to the buffer without a prefix.
+///
+/// # Panics
+///
+/// Panics if `required` is `true` and the string does not start with the
+/// prefix.
+pub const fn strip_prefix<const N: usize>(
+    buf: Buf<N>,
+    s: &str,
+    prefix: Needle,
+    required: bool,
+) -> Buf<N> {
+    if prefix.is_match(s.as_bytes(), 0) {
+        buf.push_slice(s, prefix.len(), s.len())
+    } else if required {
+        panic!("string does not start with the prefix");
+    } else {
+        buf.push_str(s)
+    }
+}
+
+/// Writes a string to the buffer without a suffix.
+///
+/// # Panics
+///
+/// Panics if `required` is `true` and the string does not end with the suffix.
+pub const fn strip_suffix<const N: usize>(
+    buf: Buf<N>,
+    s: &str,
+    suffix: Needle,
+    required: bool,
+) -> Buf<N> {
+    if s.len() >= suffix.len() && suffix.is_match(s.as_bytes(), s.len() - suffix.len()) {
+        buf.push_slice(s, 0, s.len() - suffix.len())
+    } else if required {
+        panic!("string does not end with the suffix");
+    } else {
+        buf.push_str(s)
+    }
+}
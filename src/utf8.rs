@@ -0,0 +1,89 @@
+/// Converts a Unicode scalar value to a `char` without checking it.
+///
+/// # Safety
+///
+/// `code` must be a valid Unicode scalar value, not a surrogate and at most
+/// `0x10FFFF`.
+#[allow(unknown_lints, unnecessary_transmutes)]
+pub(crate) const unsafe fn char_from_u32_unchecked(code: u32) -> char {
+    // `char::from_u32_unchecked` is not const on all supported compilers.
+    core::mem::transmute::<u32, char>(code)
+}
+
+/// Encodes a character as UTF-8, returning the bytes and the number of bytes
+/// used.
+pub(crate) const fn encode(c: char) -> ([u8; 4], usize) {
+    // UTF-8 ranges and tags for encoding characters.
+    const TAG_CONT: u8 = 0b1000_0000;
+    const TAG_TWO_B: u8 = 0b1100_0000;
+    const TAG_THREE_B: u8 = 0b1110_0000;
+    const TAG_FOUR_B: u8 = 0b1111_0000;
+
+    let code = c as u32;
+    let len = c.len_utf8();
+
+    let mut buf = [0; 4];
+    match len {
+        1 => {
+            buf[0] = code as u8;
+        }
+        2 => {
+            buf[0] = (code >> 6 & 0x1F) as u8 | TAG_TWO_B;
+            buf[1] = (code & 0x3F) as u8 | TAG_CONT;
+        }
+        3 => {
+            buf[0] = (code >> 12 & 0x0F) as u8 | TAG_THREE_B;
+            buf[1] = (code >> 6 & 0x3F) as u8 | TAG_CONT;
+            buf[2] = (code & 0x3F) as u8 | TAG_CONT;
+        }
+        4 => {
+            buf[0] = (code >> 18 & 0x07) as u8 | TAG_FOUR_B;
+            buf[1] = (code >> 12 & 0x3F) as u8 | TAG_CONT;
+            buf[2] = (code >> 6 & 0x3F) as u8 | TAG_CONT;
+            buf[3] = (code & 0x3F) as u8 | TAG_CONT;
+        }
+        _ => unreachable!(),
+    }
+
+    (buf, len)
+}
+
+/// Decodes the character starting at byte `i` of a UTF-8 string, returning the
+/// character and its length in bytes.
+pub(crate) const fn decode(bytes: &[u8], i: usize) -> (char, usize) {
+    let b0 = bytes[i] as u32;
+    let (code, len) = if b0 < 0x80 {
+        (b0, 1)
+    } else if b0 < 0xE0 {
+        ((b0 & 0x1F) << 6 | cont(bytes[i + 1]), 2)
+    } else if b0 < 0xF0 {
+        (
+            (b0 & 0x0F) << 12 | cont(bytes[i + 1]) << 6 | cont(bytes[i + 2]),
+            3,
+        )
+    } else {
+        let code = (b0 & 0x07) << 18
+            | cont(bytes[i + 1]) << 12
+            | cont(bytes[i + 2]) << 6
+            | cont(bytes[i + 3]);
+        (code, 4)
+    };
+
+    // SAFETY: The bytes are valid UTF-8, so they encode a Unicode scalar value.
+    (unsafe { char_from_u32_unchecked(code) }, len)
+}
+
+/// Decodes the character ending before byte `end` of a UTF-8 string, returning
+/// the character and its length in bytes.
+pub(crate) const fn decode_last(bytes: &[u8], end: usize) -> (char, usize) {
+    let mut start = end - 1;
+    while bytes[start] & 0b1100_0000 == 0b1000_0000 {
+        start -= 1;
+    }
+    decode(bytes, start)
+}
+
+/// Returns the value bits of a UTF-8 continuation byte.
+const fn cont(b: u8) -> u32 {
+    (b & 0x3F) as u32
+}
@@ -0,0 +1,231 @@
+use crate::buf::Buf;
+use crate::tables::{self, width as table};
+use crate::utf8;
+
+/// Pads a constant `&str` at the end to a display width, producing a constant
+/// `&str`.
+///
+/// Display width is measured as by [`display_width`](crate::display_width).
+/// The string is padded with spaces, or with the fill character given as a
+/// third argument, which must have a display width of one. Strings that are
+/// already at least as wide are left unchanged.
+///
+/// *Requires the `unicode-width` feature.*
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::{chstr_pad_end, chstr_truncate};
+/// const NAME: &str = chstr_pad_end!("名前", 8);
+///
+/// assert_eq!(NAME, "名前    ");
+/// assert_eq!(chstr_pad_end!("Total", 8, '.'), "Total...");
+///
+/// const LABEL: &str = "Configuration";
+/// const COLUMN: &str = chstr_pad_end!(chstr_truncate!(LABEL, 8, '…'), 8);
+///
+/// assert_eq!(COLUMN, "Configu…");
+/// ```
+#[macro_export]
+macro_rules! chstr_pad_end {
+    ($s:expr, $width:expr $(,)?) => {
+        $crate::chstr_pad_end!($s, $width, ' ')
+    };
+    ($s:expr, $width:expr, $fill:expr $(,)?) => {{
+        const STR: &str = $s;
+        const WIDTH: usize = $width;
+        const FILL: char = $fill;
+
+        $crate::__chstr_build!(|buf| $crate::__private::pad(buf, STR, WIDTH, FILL, false))
+    }};
+}
+
+/// Pads a constant `&str` at the start to a display width, producing a constant
+/// `&str`.
+///
+/// The string is padded as by [`chstr_pad_end!`].
+///
+/// *Requires the `unicode-width` feature.*
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_pad_start;
+/// const PRICE: &str = chstr_pad_start!("42", 6);
+///
+/// assert_eq!(PRICE, "    42");
+/// assert_eq!(chstr_pad_start!("７", 4, '0'), "00７");
+/// ```
+#[macro_export]
+macro_rules! chstr_pad_start {
+    ($s:expr, $width:expr $(,)?) => {
+        $crate::chstr_pad_start!($s, $width, ' ')
+    };
+    ($s:expr, $width:expr, $fill:expr $(,)?) => {{
+        const STR: &str = $s;
+        const WIDTH: usize = $width;
+        const FILL: char = $fill;
+
+        $crate::__chstr_build!(|buf| $crate::__private::pad(buf, STR, WIDTH, FILL, true))
+    }};
+}
+
+/// Truncates a constant `&str` to a display width, producing a constant `&str`.
+///
+/// Display width is measured as by [`display_width`](crate::display_width).
+/// Strings that are not wider than the width are left unchanged. Otherwise, as
+/// many characters as fit are kept, followed by the ellipsis given as a third
+/// argument, if any. The ellipsis may be any [`chstr!`] argument, and must not
+/// be wider than the width.
+///
+/// The result may be narrower than the width, if a wide character does not
+/// fit.
+///
+/// *Requires the `unicode-width` feature.*
+///
+/// [`chstr!`]: crate::chstr
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::chstr_truncate;
+/// const TITLE: &str = "Hello, world!";
+///
+/// assert_eq!(chstr_truncate!(TITLE, 5), "Hello");
+/// assert_eq!(chstr_truncate!(TITLE, 8, "..."), "Hello...");
+/// assert_eq!(chstr_truncate!("日本語", 5), "日本");
+/// assert_eq!(chstr_truncate!("short", 8, '…'), "short");
+/// ```
+#[macro_export]
+macro_rules! chstr_truncate {
+    ($s:expr, $width:expr $(,)?) => {
+        $crate::chstr_truncate!($s, $width, "")
+    };
+    ($s:expr, $width:expr, $ellipsis:expr $(,)?) => {{
+        const STR: &str = $s;
+        const WIDTH: usize = $width;
+        const ELLIPSIS: &str = $crate::chstr![$ellipsis];
+
+        $crate::__chstr_build!(|buf| $crate::__private::truncate(buf, STR, WIDTH, ELLIPSIS))
+    }};
+}
+
+/// Returns the number of columns a string occupies when displayed in a
+/// terminal.
+///
+/// Characters with an `East_Asian_Width` property of `Wide` or `Fullwidth`
+/// have a width of two. Control characters, combining marks, format characters
+/// other than soft hyphen, and Hangul medial vowels and final consonants have a
+/// width of zero. All other characters have a width of one.
+///
+/// *Requires the `unicode-width` feature.*
+///
+/// # Examples
+///
+/// ```
+/// # use chstr::{chstr, display_width};
+/// const WIDTH: usize = display_width("日本語");
+///
+/// assert_eq!(WIDTH, 6);
+/// assert_eq!(display_width("cafe\u{301}"), 4);
+/// assert_eq!(display_width(chstr!['a', '한', '\u{200B}']), 3);
+/// ```
+pub const fn display_width(s: &str) -> usize {
+    let bytes = s.as_bytes();
+
+    let mut width = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        width += char_width(c);
+        i += len;
+    }
+    width
+}
+
+/// Returns the number of columns a character occupies when displayed in a
+/// terminal.
+const fn char_width(c: char) -> usize {
+    if tables::in_ranges(table::ZERO_WIDTH, c) {
+        0
+    } else if tables::in_ranges(table::WIDE, c) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Writes a string to the buffer, padded at the start or end with a fill
+/// character to a display width.
+///
+/// # Panics
+///
+/// Panics if the fill character does not have a display width of one.
+pub const fn pad<const N: usize>(
+    buf: Buf<N>,
+    s: &str,
+    width: usize,
+    fill: char,
+    start: bool,
+) -> Buf<N> {
+    assert!(
+        char_width(fill) == 1,
+        "fill character must have a display width of one"
+    );
+
+    let mut buf = buf;
+    if !start {
+        buf = buf.push_str(s);
+    }
+
+    let mut padding = width.saturating_sub(display_width(s));
+    while padding > 0 {
+        buf = buf.push_char(fill);
+        padding -= 1;
+    }
+
+    if start {
+        buf = buf.push_str(s);
+    }
+    buf
+}
+
+/// Writes a string to the buffer, truncated to a display width with an
+/// ellipsis.
+///
+/// # Panics
+///
+/// Panics if the string is truncated and the ellipsis is wider than the width.
+pub const fn truncate<const N: usize>(
+    buf: Buf<N>,
+    s: &str,
+    width: usize,
+    ellipsis: &str,
+) -> Buf<N> {
+    if display_width(s) <= width {
+        return buf.push_str(s);
+    }
+
+    let ellipsis_width = display_width(ellipsis);
+    assert!(
+        ellipsis_width <= width,
+        "ellipsis is wider than the truncated width"
+    );
+    let limit = width - ellipsis_width;
+
+    let bytes = s.as_bytes();
+
+    let mut used = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, len) = utf8::decode(bytes, i);
+        let c_width = char_width(c);
+        if used + c_width > limit {
+            break;
+        }
+        used += c_width;
+        i += len;
+    }
+
+    buf.push_slice(s, 0, i).push_str(ellipsis)
+}
@@ -0,0 +1,106 @@
+use std::hash::Hasher;
+
+use chstr::{chstr_hash, hash};
+
+/// Returns test inputs of every length up to 1024 bytes, which covers each
+/// length class of the hash functions: empty, 1-3, 4-8, 9-16, 17-128, 129-240
+/// and longer inputs.
+fn inputs() -> impl Iterator<Item = Vec<u8>> {
+    let data: Vec<u8> = (0..1024u32)
+        .map(|i| (i.wrapping_mul(0x9E37_79B1) >> 13) as u8)
+        .collect();
+    (0..=data.len()).map(move |len| data[..len].to_vec())
+}
+
+fn finish(mut hasher: impl Hasher, bytes: &[u8]) -> u64 {
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+#[test]
+fn fnv1a_32() {
+    for bytes in inputs() {
+        let expected = const_fnv1a_hash::fnv1a_hash_32(&bytes, None);
+        assert_eq!(hash::fnv1a_32(&bytes), expected, "length {}", bytes.len());
+    }
+}
+
+#[test]
+fn fnv1a_64() {
+    for bytes in inputs() {
+        let expected = finish(fnv::FnvHasher::default(), &bytes);
+        assert_eq!(hash::fnv1a_64(&bytes), expected, "length {}", bytes.len());
+    }
+}
+
+#[cfg(all(target_pointer_width = "64", target_endian = "little"))]
+#[test]
+fn fx_hash() {
+    for bytes in inputs() {
+        let expected = finish(rustc_hash::FxHasher::default(), &bytes);
+        assert_eq!(hash::fx_hash(&bytes), expected, "length {}", bytes.len());
+    }
+}
+
+#[test]
+fn xxh64() {
+    for bytes in inputs() {
+        for seed in [0, 1, 0xDEAD_BEEF, u64::MAX] {
+            let expected = xxhash_rust::xxh64::xxh64(&bytes, seed);
+            assert_eq!(
+                hash::xxh64(&bytes, seed),
+                expected,
+                "length {}",
+                bytes.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn xxh3_64() {
+    for bytes in inputs() {
+        let expected = xxhash_rust::xxh3::xxh3_64(&bytes);
+        assert_eq!(hash::xxh3_64(&bytes), expected, "length {}", bytes.len());
+    }
+}
+
+#[test]
+fn sip13() {
+    for bytes in inputs() {
+        for (k0, k1) in [(0, 0), (1, 2), (0xDEAD_BEEF, u64::MAX)] {
+            let expected = finish(siphasher::sip::SipHasher13::new_with_keys(k0, k1), &bytes);
+            assert_eq!(
+                hash::sip13(&bytes, k0, k1),
+                expected,
+                "length {}",
+                bytes.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn chstr_hash() {
+    const LONG: &str = chstr::chstr!["0123456789"; 30];
+
+    const FNV1A_32: (&str, u32) = chstr_hash!(fnv1a_32; "key");
+    const FNV1A_64: (&str, u64) = chstr_hash!(fnv1a_64; "key");
+    const FX_HASH: (&str, u64) = chstr_hash!(fx_hash; "key");
+    const XXH64: (&str, u64) = chstr_hash!(xxh64(7); LONG);
+    const XXH3_64: (&str, u64) = chstr_hash!(xxh3_64; LONG);
+    const SIP13: (&str, u64) = chstr_hash!(sip13(1, 2); LONG);
+
+    assert_eq!(FNV1A_32.1, const_fnv1a_hash::fnv1a_hash_32(b"key", None));
+    assert_eq!(FNV1A_64.1, finish(fnv::FnvHasher::default(), b"key"));
+    assert_eq!(FX_HASH.1, hash::fx_hash(b"key"));
+    assert_eq!(XXH64.1, xxhash_rust::xxh64::xxh64(LONG.as_bytes(), 7));
+    assert_eq!(XXH3_64.1, xxhash_rust::xxh3::xxh3_64(LONG.as_bytes()));
+    assert_eq!(
+        SIP13.1,
+        finish(
+            siphasher::sip::SipHasher13::new_with_keys(1, 2),
+            LONG.as_bytes()
+        )
+    );
+}
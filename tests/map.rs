@@ -0,0 +1,96 @@
+use chstr::{chstr_map, Map};
+
+static MAP: Map<usize> = chstr_map! {
+    "key0" => 0, "key1" => 1, "key2" => 2, "key3" => 3, "key4" => 4,
+    "key5" => 5, "key6" => 6, "key7" => 7, "key8" => 8, "key9" => 9,
+    "key10" => 10, "key11" => 11, "key12" => 12, "key13" => 13, "key14" => 14,
+    "key15" => 15, "key16" => 16, "key17" => 17, "key18" => 18, "key19" => 19,
+    "key20" => 20, "key21" => 21, "key22" => 22, "key23" => 23, "key24" => 24,
+    "key25" => 25, "key26" => 26, "key27" => 27, "key28" => 28, "key29" => 29,
+    "key30" => 30, "key31" => 31, "key32" => 32, "key33" => 33, "key34" => 34,
+    "key35" => 35, "key36" => 36, "key37" => 37, "key38" => 38, "key39" => 39,
+    "key40" => 40, "key41" => 41, "key42" => 42, "key43" => 43, "key44" => 44,
+    "key45" => 45, "key46" => 46, "key47" => 47, "key48" => 48, "key49" => 49,
+    "key50" => 50, "key51" => 51, "key52" => 52, "key53" => 53, "key54" => 54,
+    "key55" => 55, "key56" => 56, "key57" => 57, "key58" => 58, "key59" => 59,
+    "key60" => 60, "key61" => 61, "key62" => 62, "key63" => 63, "key64" => 64,
+    "key65" => 65, "key66" => 66, "key67" => 67, "key68" => 68, "key69" => 69,
+    "key70" => 70, "key71" => 71, "key72" => 72, "key73" => 73, "key74" => 74,
+    "key75" => 75, "key76" => 76, "key77" => 77, "key78" => 78, "key79" => 79,
+    "key80" => 80, "key81" => 81, "key82" => 82, "key83" => 83, "key84" => 84,
+    "key85" => 85, "key86" => 86, "key87" => 87, "key88" => 88, "key89" => 89,
+    "key90" => 90, "key91" => 91, "key92" => 92, "key93" => 93, "key94" => 94,
+    "key95" => 95, "key96" => 96, "key97" => 97, "key98" => 98, "key99" => 99,
+    "key100" => 100, "key101" => 101, "key102" => 102, "key103" => 103, "key104" => 104,
+    "key105" => 105, "key106" => 106, "key107" => 107, "key108" => 108, "key109" => 109,
+    "key110" => 110, "key111" => 111, "key112" => 112, "key113" => 113, "key114" => 114,
+    "key115" => 115, "key116" => 116, "key117" => 117, "key118" => 118, "key119" => 119,
+    "key120" => 120, "key121" => 121, "key122" => 122, "key123" => 123, "key124" => 124,
+    "key125" => 125, "key126" => 126, "key127" => 127, "key128" => 128, "key129" => 129,
+    "key130" => 130, "key131" => 131, "key132" => 132, "key133" => 133, "key134" => 134,
+    "key135" => 135, "key136" => 136, "key137" => 137, "key138" => 138, "key139" => 139,
+    "key140" => 140, "key141" => 141, "key142" => 142, "key143" => 143, "key144" => 144,
+    "key145" => 145, "key146" => 146, "key147" => 147, "key148" => 148, "key149" => 149,
+    "key150" => 150, "key151" => 151, "key152" => 152, "key153" => 153, "key154" => 154,
+    "key155" => 155, "key156" => 156, "key157" => 157, "key158" => 158, "key159" => 159,
+    "key160" => 160, "key161" => 161, "key162" => 162, "key163" => 163, "key164" => 164,
+    "key165" => 165, "key166" => 166, "key167" => 167, "key168" => 168, "key169" => 169,
+    "key170" => 170, "key171" => 171, "key172" => 172, "key173" => 173, "key174" => 174,
+    "key175" => 175, "key176" => 176, "key177" => 177, "key178" => 178, "key179" => 179,
+    "key180" => 180, "key181" => 181, "key182" => 182, "key183" => 183, "key184" => 184,
+    "key185" => 185, "key186" => 186, "key187" => 187, "key188" => 188, "key189" => 189,
+    "key190" => 190, "key191" => 191, "key192" => 192, "key193" => 193, "key194" => 194,
+    "key195" => 195, "key196" => 196, "key197" => 197, "key198" => 198, "key199" => 199,
+    "key200" => 200, "key201" => 201, "key202" => 202, "key203" => 203, "key204" => 204,
+    "key205" => 205, "key206" => 206, "key207" => 207, "key208" => 208, "key209" => 209,
+    "key210" => 210, "key211" => 211, "key212" => 212, "key213" => 213, "key214" => 214,
+    "key215" => 215, "key216" => 216, "key217" => 217, "key218" => 218, "key219" => 219,
+    "key220" => 220, "key221" => 221, "key222" => 222, "key223" => 223, "key224" => 224,
+    "key225" => 225, "key226" => 226, "key227" => 227, "key228" => 228, "key229" => 229,
+    "key230" => 230, "key231" => 231, "key232" => 232, "key233" => 233, "key234" => 234,
+    "key235" => 235, "key236" => 236, "key237" => 237, "key238" => 238, "key239" => 239,
+    "key240" => 240, "key241" => 241, "key242" => 242, "key243" => 243, "key244" => 244,
+    "key245" => 245, "key246" => 246, "key247" => 247, "key248" => 248, "key249" => 249,
+    "key250" => 250, "key251" => 251, "key252" => 252, "key253" => 253, "key254" => 254,
+    "key255" => 255, "key256" => 256, "key257" => 257, "key258" => 258, "key259" => 259,
+    "key260" => 260, "key261" => 261, "key262" => 262, "key263" => 263, "key264" => 264,
+    "key265" => 265, "key266" => 266, "key267" => 267, "key268" => 268, "key269" => 269,
+    "key270" => 270, "key271" => 271, "key272" => 272, "key273" => 273, "key274" => 274,
+    "key275" => 275, "key276" => 276, "key277" => 277, "key278" => 278, "key279" => 279,
+    "key280" => 280, "key281" => 281, "key282" => 282, "key283" => 283, "key284" => 284,
+    "key285" => 285, "key286" => 286, "key287" => 287, "key288" => 288, "key289" => 289,
+    "key290" => 290, "key291" => 291, "key292" => 292, "key293" => 293, "key294" => 294,
+    "key295" => 295, "key296" => 296, "key297" => 297, "key298" => 298, "key299" => 299,
+    "key300" => 300, "key301" => 301, "key302" => 302, "key303" => 303, "key304" => 304,
+    "key305" => 305, "key306" => 306, "key307" => 307, "key308" => 308, "key309" => 309,
+    "key310" => 310, "key311" => 311, "key312" => 312, "key313" => 313, "key314" => 314,
+    "key315" => 315, "key316" => 316, "key317" => 317, "key318" => 318, "key319" => 319,
+    "key320" => 320, "key321" => 321, "key322" => 322, "key323" => 323, "key324" => 324,
+    "key325" => 325, "key326" => 326, "key327" => 327, "key328" => 328, "key329" => 329,
+    "key330" => 330, "key331" => 331, "key332" => 332, "key333" => 333, "key334" => 334,
+    "key335" => 335, "key336" => 336, "key337" => 337, "key338" => 338, "key339" => 339,
+    "key340" => 340, "key341" => 341, "key342" => 342, "key343" => 343, "key344" => 344,
+    "key345" => 345, "key346" => 346, "key347" => 347, "key348" => 348, "key349" => 349,
+    "key350" => 350, "key351" => 351, "key352" => 352, "key353" => 353, "key354" => 354,
+    "key355" => 355, "key356" => 356, "key357" => 357, "key358" => 358, "key359" => 359,
+    "key360" => 360, "key361" => 361, "key362" => 362, "key363" => 363, "key364" => 364,
+    "key365" => 365, "key366" => 366, "key367" => 367, "key368" => 368, "key369" => 369,
+    "key370" => 370, "key371" => 371, "key372" => 372, "key373" => 373, "key374" => 374,
+    "key375" => 375, "key376" => 376, "key377" => 377, "key378" => 378, "key379" => 379,
+    "key380" => 380, "key381" => 381, "key382" => 382, "key383" => 383, "key384" => 384,
+    "key385" => 385, "key386" => 386, "key387" => 387, "key388" => 388, "key389" => 389,
+    "key390" => 390, "key391" => 391, "key392" => 392, "key393" => 393, "key394" => 394,
+    "key395" => 395, "key396" => 396, "key397" => 397, "key398" => 398, "key399" => 399,
+};
+
+#[test]
+fn map_large() {
+    assert_eq!(MAP.len(), 400);
+
+    for i in 0..400 {
+        let key = format!("key{}", i);
+        assert_eq!(MAP.get(&key), Some(&i));
+    }
+    assert_eq!(MAP.get("key400"), None);
+    assert_eq!(MAP.get(""), None);
+}
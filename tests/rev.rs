@@ -0,0 +1,20 @@
+use chstr::{chstr, chstr_rev};
+
+#[test]
+fn rev_long() {
+    const INPUT: &str = chstr!["abcé"; 1000];
+    const REVERSED: &str = chstr_rev![INPUT];
+
+    assert_eq!(REVERSED, INPUT.chars().rev().collect::<String>());
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn rev_graphemes_long() {
+    use chstr::chstr_rev_graphemes;
+
+    const INPUT: &str = chstr!["ab\u{301} 🇳🇿👩‍👩‍👧"; 1000];
+    const REVERSED: &str = chstr_rev_graphemes![INPUT];
+
+    assert_eq!(REVERSED, chstr!["👩‍👩‍👧🇳🇿 b\u{301}a"; 1000]);
+}